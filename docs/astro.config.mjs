@@ -49,15 +49,43 @@ export default defineConfig({
                   label: "Create column families",
                   link: "/reference/json-api/create-column-families",
                 },
+                {
+                  label: "Alter column families",
+                  link: "/reference/json-api/alter-column-families",
+                },
                 {
                   label: "Ingest data",
                   link: "/reference/json-api/ingest-data",
                 },
+                {
+                  label: "Check and mutate",
+                  link: "/reference/json-api/check-and-mutate",
+                },
+                {
+                  label: "Read-modify-write",
+                  link: "/reference/json-api/read-modify-write",
+                },
                 {
                   label: "Retrieve rows",
                   link: "/reference/json-api/retrieve-rows",
                 },
                 { label: "Scan rows", link: "/reference/json-api/scan-rows" },
+                {
+                  label: "Aggregate rows",
+                  link: "/reference/json-api/aggregate",
+                },
+                {
+                  label: "Row filters",
+                  link: "/reference/json-api/row-filters",
+                },
+                {
+                  label: "Delete rows",
+                  link: "/reference/json-api/delete-rows",
+                },
+                {
+                  label: "Table metrics",
+                  link: "/reference/json-api/table-metrics",
+                },
               ],
             },
           ],
@@ -0,0 +1,85 @@
+use crate::app_state::AppState;
+use crate::error::CustomRouteResult;
+use crate::identifier::is_valid_table_identifier;
+use crate::response::build_response;
+use actix_web::http::StatusCode;
+use actix_web::{
+    post,
+    web::{self, Path},
+    HttpResponse,
+};
+use serde_json::json;
+use smoltable::query::aggregate::Input as AggregateInput;
+
+#[post("/v1/table/{name}/aggregate")]
+pub async fn handler(
+    path: Path<String>,
+    app_state: web::Data<AppState>,
+    req_body: web::Json<AggregateInput>,
+) -> CustomRouteResult<HttpResponse> {
+    let before = std::time::Instant::now();
+
+    let table_name = path.into_inner();
+
+    if table_name.starts_with('_') {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    if !is_valid_table_identifier(&table_name) {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    let tables = app_state.tables.read().await;
+
+    if let Some(table) = tables.get(&table_name) {
+        let result = {
+            let table = table.clone();
+
+            tokio::task::spawn_blocking(move || table.aggregate(req_body.0))
+                .await
+                .expect("should join")
+        }?;
+
+        let dur = before.elapsed();
+
+        let micros_total = dur.as_micros();
+
+        let micros_per_row = if result.row_count == 0 {
+            None
+        } else {
+            Some(micros_total / result.row_count as u128)
+        };
+
+        Ok(build_response(
+            dur,
+            StatusCode::OK,
+            "Aggregation successful",
+            &json!({
+                "groups": result.groups,
+                "row_count": result.row_count,
+                "cell_count": result.cell_count,
+                "micros": micros_total,
+                "micros_per_row": micros_per_row,
+                "bytes_scanned": result.bytes_scanned_count,
+                "is_group_limit_reached": result.is_group_limit_reached,
+            }),
+        ))
+    } else {
+        Ok(build_response(
+            before.elapsed(),
+            StatusCode::NOT_FOUND,
+            "Table not found",
+            &json!(null),
+        ))
+    }
+}
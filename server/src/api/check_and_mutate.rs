@@ -0,0 +1,83 @@
+use crate::app_state::AppState;
+use crate::data_point;
+use crate::error::CustomRouteResult;
+use crate::identifier::is_valid_table_identifier;
+use crate::response::build_response;
+use actix_web::http::StatusCode;
+use actix_web::{
+    post,
+    web::{self, Path},
+    HttpResponse,
+};
+use serde_json::json;
+use smoltable::{query::check_and_mutate::Input as CheckAndMutateInput, TableWriter};
+
+#[post("/v1/table/{name}/check-and-mutate")]
+pub async fn handler(
+    path: Path<String>,
+    app_state: web::Data<AppState>,
+    req_body: web::Json<CheckAndMutateInput>,
+) -> CustomRouteResult<HttpResponse> {
+    let before = std::time::Instant::now();
+
+    let table_name = path.into_inner();
+
+    if table_name.starts_with('_') {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    if !is_valid_table_identifier(&table_name) {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    let tables = app_state.tables.read().await;
+
+    if let Some(table) = tables.get(&table_name) {
+        let result = {
+            let table = table.clone();
+
+            tokio::task::spawn_blocking(move || table.check_and_mutate(req_body.into_inner()))
+                .await
+                .expect("should join")
+        }?;
+
+        let dur = before.elapsed();
+
+        let micros_total = dur.as_micros();
+
+        TableWriter::write_batch(
+            table.metrics.clone(),
+            &[smoltable::row!(
+                "lat#write#cond",
+                vec![data_point!(micros_total as f64)]
+            )],
+        )
+        .ok();
+
+        Ok(build_response(
+            dur,
+            StatusCode::OK,
+            "Conditional mutation successful",
+            &json!({
+                "predicate_matched": result.predicate_matched
+            }),
+        ))
+    } else {
+        Ok(build_response(
+            before.elapsed(),
+            StatusCode::NOT_FOUND,
+            "Table not found",
+            &json!(null),
+        ))
+    }
+}
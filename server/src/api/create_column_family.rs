@@ -10,18 +10,23 @@ use actix_web::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use smoltable::{ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions};
+use smoltable::{
+    ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions,
+    LocalityGroupOptions, ValueTypeDefinition,
+};
 
 #[derive(Debug, Deserialize, Serialize)]
 struct ColumnFamilyDefinitionInput {
     pub name: String,
     pub gc_settings: Option<GarbageCollectionOptions>,
+    pub value_type: Option<ValueTypeDefinition>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Input {
     pub column_families: Vec<ColumnFamilyDefinitionInput>,
     pub locality_group: Option<bool>,
+    pub locality_group_options: Option<LocalityGroupOptions>,
 }
 
 impl From<ColumnFamilyDefinitionInput> for ColumnFamilyDefinition {
@@ -29,6 +34,7 @@ impl From<ColumnFamilyDefinitionInput> for ColumnFamilyDefinition {
         ColumnFamilyDefinition {
             name: val.name,
             gc_settings: val.gc_settings.unwrap_or_default(),
+            value_type: val.value_type,
         }
     }
 }
@@ -91,6 +97,7 @@ pub async fn handler(
         table.create_column_families(&CreateColumnFamilyInput {
             column_families,
             locality_group: req_body.0.locality_group,
+            locality_group_options: req_body.0.locality_group_options,
         })?;
 
         Ok(build_response(
@@ -7,14 +7,22 @@ use actix_web::{
     web::{self, Path},
     HttpResponse,
 };
+use serde::Deserialize;
 use serde_json::json;
+use smoltable::SchemaMode;
 
-// TODO: allow setting dedicated cache size
+#[derive(Debug, Default, Deserialize)]
+pub struct Input {
+    /// Whether writes to undefined column families are rejected (default: strict)
+    #[serde(default)]
+    schema_mode: SchemaMode,
+}
 
 #[put("/v1/table/{name}")]
 pub async fn handler(
     path: Path<String>,
     app_state: web::Data<AppState>,
+    req_body: Option<web::Json<Input>>,
 ) -> CustomRouteResult<HttpResponse> {
     let before = std::time::Instant::now();
 
@@ -49,7 +57,11 @@ pub async fn handler(
     }
     drop(tables);
 
-    app_state.create_table(&table_name).await?;
+    let input = req_body.map(web::Json::into_inner).unwrap_or_default();
+
+    app_state
+        .create_table(&table_name, input.schema_mode)
+        .await?;
 
     Ok(build_response(
         before.elapsed(),
@@ -0,0 +1,76 @@
+use crate::app_state::AppState;
+use crate::error::CustomRouteResult;
+use crate::identifier::is_valid_table_identifier;
+use crate::response::build_response;
+use actix_web::http::StatusCode;
+use actix_web::{
+    delete,
+    web::{self, Path},
+    HttpResponse,
+};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+struct Input {
+    pub name: String,
+}
+
+#[delete("/v1/table/{name}/column-family")]
+pub async fn handler(
+    path: Path<String>,
+    app_state: web::Data<AppState>,
+    req_body: web::Json<Input>,
+) -> CustomRouteResult<HttpResponse> {
+    let before = std::time::Instant::now();
+
+    let table_name = path.into_inner();
+
+    if table_name.starts_with('_') {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::FORBIDDEN,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    if !is_valid_table_identifier(&table_name) {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    let tables = app_state.tables.read().await;
+
+    if let Some(table) = tables.get(&table_name).cloned() {
+        drop(tables);
+
+        let deleted_cells_count = {
+            let family = req_body.into_inner().name;
+
+            tokio::task::spawn_blocking(move || table.delete_column_family(&family))
+                .await
+                .expect("should join")?
+        };
+
+        Ok(build_response(
+            before.elapsed(),
+            StatusCode::OK,
+            "Column family deleted successfully",
+            &json!({
+                "deleted_cells_count": deleted_cells_count
+            }),
+        ))
+    } else {
+        Ok(build_response(
+            before.elapsed(),
+            StatusCode::NOT_FOUND,
+            "Table not found",
+            &json!(null),
+        ))
+    }
+}
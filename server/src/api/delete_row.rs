@@ -11,16 +11,15 @@ use actix_web::{
 };
 use serde::Deserialize;
 use serde_json::json;
-use smoltable::{ColumnFilter, TableWriter};
+use smoltable::{ColumnFilter, RowFilter, TableWriter};
 
 #[derive(Debug, Deserialize)]
 pub struct Input {
     row_key: String,
     column_filter: Option<ColumnFilter>,
+    filter: Option<RowFilter>,
 }
 
-// TODO: change input format to Vec, atomic multi-row deletes...?
-
 #[delete("/v1/table/{name}/row")]
 pub async fn handler(
     path: Path<String>,
@@ -58,7 +57,7 @@ pub async fn handler(
             let table = table.clone();
 
             tokio::task::spawn_blocking(move || {
-                table.delete_row(req_body.row_key, req_body.column_filter)
+                table.delete_row_filtered(req_body.row_key, req_body.column_filter, req_body.filter)
             })
             .await
             .expect("should join")
@@ -5,7 +5,7 @@ use actix_web::http::StatusCode;
 use actix_web::{get, web, HttpResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use smoltable::{ColumnFamilyDefinition, BLOCK_SIZE};
+use smoltable::{ColumnFamilyDefinition, LocalityGroupOptions};
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -19,6 +19,9 @@ struct CacheStats {
 struct LocalityGroupListEntry {
     id: Arc<str>,
     column_families: Vec<Arc<str>>,
+    options: LocalityGroupOptions,
+    disk_space_in_bytes: u64,
+    segment_count: usize,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -56,6 +59,9 @@ pub async fn handler(app_state: web::Data<AppState>) -> CustomRouteResult<HttpRe
                     .map(|x| LocalityGroupListEntry {
                         id: x.id.clone(),
                         column_families: x.column_families.clone(),
+                        options: x.options.clone(),
+                        disk_space_in_bytes: x.tree.disk_space(),
+                        segment_count: x.tree.segment_count(),
                     })
                     .collect::<Vec<_>>(),
                 disk_space_in_bytes: table.disk_space_usage(),
@@ -89,11 +95,10 @@ pub async fn handler(app_state: web::Data<AppState>) -> CustomRouteResult<HttpRe
         })
         .collect::<smoltable::Result<Vec<_>>>()?;
 
-    let cached_block_count = app_state.block_cache.len();
-
+    // NOTE: Locality groups may use different block sizes, so the cache is asked for its size
     let cache_stats = CacheStats {
-        block_count: cached_block_count,
-        memory_usage_in_bytes: cached_block_count as u64 * u64::from(BLOCK_SIZE),
+        block_count: app_state.block_cache.len(),
+        memory_usage_in_bytes: app_state.block_cache.size(),
     };
 
     Ok(build_response(
@@ -1,40 +1,143 @@
+use super::bad_request;
 use crate::app_state::AppState;
 use crate::error::CustomRouteResult;
+use crate::identifier::is_valid_table_identifier;
+use crate::metrics::{downsample, TABLE_METRIC_SERIES};
 use crate::response::build_response;
 use actix_web::http::StatusCode;
 use actix_web::{
     get,
-    web::{self, Path},
+    web::{self, Path, Query},
     HttpResponse,
 };
+use serde::Deserialize;
 use serde_json::json;
+use smoltable::{
+    query::{
+        row::{CellOptions, ColumnOptions, Input as QueryRowInput, RowOptions},
+        scan::TimeRange,
+    },
+    ColumnFilter, ColumnKey,
+};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Deserialize)]
+pub struct Input {
+    /// Start of the time window in nanoseconds since Unix epoch (inclusive)
+    start: Option<u64>,
+
+    /// End of the time window in nanoseconds since Unix epoch (exclusive)
+    end: Option<u64>,
+
+    /// Bucket size in seconds, data points in a bucket are averaged
+    bucket_secs: Option<u64>,
+
+    /// Comma-separated list of series, all series are returned if not set
+    series: Option<String>,
+}
 
 #[get("/v1/table/{name}/metrics")]
 pub async fn handler(
     path: Path<String>,
     app_state: web::Data<AppState>,
+    query: Query<Input>,
 ) -> CustomRouteResult<HttpResponse> {
     let before = std::time::Instant::now();
 
     let table_name = path.into_inner();
 
-    let actual_name = format!("usr_{table_name}");
+    if table_name.starts_with('_') {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
 
-    let tables = app_state.tables.write().await;
+    if !is_valid_table_identifier(&table_name) {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
 
-    if tables.get(&actual_name).is_some() {
-        /* let rows = app_state
-        .metrics_table
-        .query_timeseries(&format!("t#{actual_name}"), None)?; */
+    let series = match &query.series {
+        Some(series) => series.split(',').map(str::trim).collect::<Vec<_>>(),
+        None => TABLE_METRIC_SERIES.to_vec(),
+    };
 
-        unimplemented!()
+    if let Some(name) = series
+        .iter()
+        .find(|name| !TABLE_METRIC_SERIES.contains(name))
+    {
+        return bad_request(before, &format!("Unknown series {name:?}"));
+    }
+
+    let tables = app_state.tables.read().await;
+
+    if let Some(table) = tables.get(&table_name) {
+        let time_range = TimeRange {
+            start: query.start.map(u128::from),
+            end: query.end.map(u128::from),
+        };
+
+        let inputs = series
+            .iter()
+            .map(|name| QueryRowInput {
+                row: RowOptions {
+                    key: (*name).to_owned(),
+                    cell_limit: Some(u32::MAX),
+                },
+                column: Some(ColumnOptions {
+                    filter: Some(ColumnFilter::Key(
+                        ColumnKey::try_from("value:").expect("should be valid column key"),
+                    )),
+                    cell_limit: Some(u32::MAX),
+                    limit: None,
+                    start_after: None,
+                }),
+                cell: Some(CellOptions {
+                    time: Some(time_range.clone()),
+                }),
+                filter: None,
+            })
+            .collect::<Vec<_>>();
+
+        let result = {
+            let metrics = table.metrics.clone();
+
+            tokio::task::spawn_blocking(move || metrics.multi_get(inputs))
+                .await
+                .expect("should join")
+        }?;
 
-        /* Ok(build_response(
+        let bucket_nanos = query
+            .bucket_secs
+            .map(|secs| u128::from(secs) * 1_000_000_000);
+
+        let mut time_series: BTreeMap<&str, _> =
+            series.iter().map(|&name| (name, vec![])).collect();
+
+        for row in &result.rows {
+            if let Some(cells) = row.columns.get("value").and_then(|family| family.get("")) {
+                if let Some(points) = time_series.get_mut(row.row_key.as_str()) {
+                    *points = downsample(cells, bucket_nanos);
+                }
+            }
+        }
+
+        Ok(build_response(
             before.elapsed(),
             StatusCode::OK,
             "Metrics query successful",
-            &json!(rows),
-        )) */
+            &json!({
+                "series": time_series,
+                "bytes_scanned": result.bytes_scanned_count,
+            }),
+        ))
     } else {
         Ok(build_response(
             before.elapsed(),
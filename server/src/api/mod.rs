@@ -1,12 +1,20 @@
+pub mod aggregate;
+pub mod check_and_mutate;
 pub mod count;
 pub mod create_column_family;
 pub mod create_table;
+pub mod delete_column_family;
 pub mod delete_row;
+pub mod delete_rows;
 pub mod delete_table;
 pub mod get_rows;
 pub mod list_tables;
 pub mod metrics;
+pub mod read_modify_write;
+pub mod regroup_column_families;
 pub mod scan;
+pub mod scan_stream;
+pub mod update_column_family;
 pub mod write;
 
 use crate::{error::CustomRouteResult, response::build_response};
@@ -0,0 +1,71 @@
+use crate::app_state::AppState;
+use crate::error::CustomRouteResult;
+use crate::identifier::is_valid_table_identifier;
+use crate::response::build_response;
+use actix_web::http::StatusCode;
+use actix_web::{
+    post,
+    web::{self, Path},
+    HttpResponse,
+};
+use serde_json::json;
+use smoltable::RegroupColumnFamiliesInput;
+
+#[post("/v1/table/{name}/column-family/regroup")]
+pub async fn handler(
+    path: Path<String>,
+    app_state: web::Data<AppState>,
+    req_body: web::Json<RegroupColumnFamiliesInput>,
+) -> CustomRouteResult<HttpResponse> {
+    let before = std::time::Instant::now();
+
+    let table_name = path.into_inner();
+
+    if table_name.starts_with('_') {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::FORBIDDEN,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    if !is_valid_table_identifier(&table_name) {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    let tables = app_state.tables.read().await;
+
+    if let Some(table) = tables.get(&table_name).cloned() {
+        drop(tables);
+
+        let moved_cells_count = {
+            let input = req_body.into_inner();
+
+            tokio::task::spawn_blocking(move || table.regroup_column_families(&input))
+                .await
+                .expect("should join")?
+        };
+
+        Ok(build_response(
+            before.elapsed(),
+            StatusCode::OK,
+            "Column families regrouped successfully",
+            &json!({
+                "moved_cells_count": moved_cells_count
+            }),
+        ))
+    } else {
+        Ok(build_response(
+            before.elapsed(),
+            StatusCode::NOT_FOUND,
+            "Table not found",
+            &json!(null),
+        ))
+    }
+}
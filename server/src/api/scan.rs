@@ -89,7 +89,9 @@ pub async fn handler(
                 "bytes_scanned": result.bytes_scanned_count,
                 "row_count": result.rows.len(),
                 "cell_count": cell_count,
-                "rows": result.rows
+                "rows": result.rows,
+                "continuation": result.continuation,
+                "column_cursor": result.column_cursor
             }),
         ))
     } else {
@@ -0,0 +1,171 @@
+use crate::app_state::AppState;
+use crate::data_point;
+use crate::error::{CustomHttpError, CustomRouteResult};
+use crate::identifier::is_valid_table_identifier;
+use crate::response::build_response;
+use actix_web::body::{BodySize, MessageBody};
+use actix_web::http::StatusCode;
+use actix_web::{
+    post,
+    web::{self, Bytes, Path},
+    HttpResponse,
+};
+use serde::Serialize;
+use serde_json::json;
+use smoltable::{query::scan::Input as QueryPrefixInput, TableWriter};
+use std::{
+    convert::Infallible,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc;
+
+/// Amount of rows that are buffered before the scan waits for the client to catch up
+const STREAM_BUFFER_SIZE: usize = 64;
+
+fn to_line<T: Serialize>(record: &T) -> Bytes {
+    let mut line = serde_json::to_vec(record).expect("should serialize");
+    line.push(b'\n');
+    line.into()
+}
+
+fn error_line(error: smoltable::Error) -> Bytes {
+    to_line(&json!({
+        "error": CustomHttpError::from(error).client_message(),
+    }))
+}
+
+/// Newline-delimited JSON body that is sent as the scan produces rows
+///
+/// The body is only polled when the socket can take more data,
+/// so a slow client pauses the scan once the buffer is full.
+struct NdjsonBody {
+    first: Option<Bytes>,
+    rx: mpsc::Receiver<smoltable::Result<Bytes>>,
+}
+
+impl MessageBody for NdjsonBody {
+    type Error = Infallible;
+
+    fn size(&self) -> BodySize {
+        BodySize::Stream
+    }
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Bytes, Self::Error>>> {
+        let this = self.get_mut();
+
+        if let Some(line) = this.first.take() {
+            return Poll::Ready(Some(Ok(line)));
+        }
+
+        this.rx
+            .poll_recv(cx)
+            .map(|item| item.map(|line| Ok(line.unwrap_or_else(error_line))))
+    }
+}
+
+#[post("/v1/table/{name}/scan/stream")]
+pub async fn handler(
+    path: Path<String>,
+    app_state: web::Data<AppState>,
+    req_body: web::Json<QueryPrefixInput>,
+) -> CustomRouteResult<HttpResponse> {
+    let before = std::time::Instant::now();
+
+    let table_name = path.into_inner();
+
+    if table_name.starts_with('_') {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    if !is_valid_table_identifier(&table_name) {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    let tables = app_state.tables.read().await;
+
+    let Some(table) = tables.get(&table_name).cloned() else {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::NOT_FOUND,
+            "Table not found",
+            &json!(null),
+        ));
+    };
+
+    drop(tables);
+
+    let (tx, mut rx) = mpsc::channel(STREAM_BUFFER_SIZE);
+
+    tokio::task::spawn_blocking(move || {
+        let mut row_count = 0;
+        let mut cell_count = 0;
+
+        let result = table.scan_each(req_body.into_inner(), |row| {
+            row_count += 1;
+            cell_count += row.cell_count();
+
+            // NOTE: If the client disconnected, the scan is stopped
+            tx.blocking_send(Ok(to_line(&row))).is_ok()
+        });
+
+        let stats = match result {
+            Ok(stats) => stats,
+            Err(error) => {
+                tx.blocking_send(Err(error)).ok();
+                return;
+            }
+        };
+
+        let micros_total = before.elapsed().as_micros();
+
+        TableWriter::write_batch(
+            table.metrics.clone(),
+            &[smoltable::row!(
+                "lat#read#pfx",
+                vec![data_point!(micros_total as f64)]
+            )],
+        )
+        .ok();
+
+        tx.blocking_send(Ok(to_line(&json!({
+            "stats": {
+                "affected_locality_groups": stats.affected_locality_groups,
+                "micros": micros_total,
+                "rows_scanned": stats.rows_scanned_count,
+                "cells_scanned": stats.cells_scanned_count,
+                "bytes_scanned": stats.bytes_scanned_count,
+                "row_count": row_count,
+                "cell_count": cell_count,
+                "continuation": stats.continuation,
+                "column_cursor": stats.column_cursor,
+            }
+        }))))
+        .ok();
+    });
+
+    // NOTE: Errors before the first row can still be returned with a proper status code
+    let first = match rx.recv().await {
+        Some(Ok(line)) => Some(line),
+        Some(Err(error)) => return Err(error.into()),
+        None => None,
+    };
+
+    Ok(HttpResponse::Ok()
+        .append_header(("x-server", super::format_server_header()))
+        .content_type("application/x-ndjson")
+        .body(NdjsonBody { first, rx }))
+}
@@ -0,0 +1,70 @@
+use crate::app_state::AppState;
+use crate::error::CustomRouteResult;
+use crate::identifier::is_valid_table_identifier;
+use crate::response::build_response;
+use actix_web::http::StatusCode;
+use actix_web::{
+    patch,
+    web::{self, Path},
+    HttpResponse,
+};
+use serde::Deserialize;
+use serde_json::json;
+use smoltable::GarbageCollectionOptions;
+
+#[derive(Debug, Deserialize)]
+struct Input {
+    pub name: String,
+    pub gc_settings: GarbageCollectionOptions,
+}
+
+#[patch("/v1/table/{name}/column-family")]
+pub async fn handler(
+    path: Path<String>,
+    app_state: web::Data<AppState>,
+    req_body: web::Json<Input>,
+) -> CustomRouteResult<HttpResponse> {
+    let before = std::time::Instant::now();
+
+    let table_name = path.into_inner();
+
+    if table_name.starts_with('_') {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::FORBIDDEN,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    if !is_valid_table_identifier(&table_name) {
+        return Ok(build_response(
+            before.elapsed(),
+            StatusCode::BAD_REQUEST,
+            "Invalid table name",
+            &json!(null),
+        ));
+    }
+
+    let tables = app_state.tables.write().await;
+
+    if let Some(table) = tables.get(&table_name) {
+        let input = req_body.into_inner();
+
+        table.update_column_family(&input.name, input.gc_settings)?;
+
+        Ok(build_response(
+            before.elapsed(),
+            StatusCode::OK,
+            "Column family updated successfully",
+            &json!(null),
+        ))
+    } else {
+        Ok(build_response(
+            before.elapsed(),
+            StatusCode::NOT_FOUND,
+            "Table not found",
+            &json!(null),
+        ))
+    }
+}
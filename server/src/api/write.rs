@@ -61,24 +61,11 @@ pub async fn handler(
 
         drop(tables);
 
+        // NOTE: Check the entire batch first, so all undefined column families are reported
+        table.check_column_families(&req_body.items)?;
+
         for row in &req_body.items {
-            // TODO:
-            /*  for cell in &row.cells {
-                if !table.column_family_exists(&actual_name, &cell.column_key.family)? {
-                    return bad_request(before, "Column family does not exist");
-                }
-            } */
-
-            if let Err(write_error) = writer.write(row) {
-                log::error!("Write error: {write_error:#?}");
-
-                return Ok(build_response(
-                    before.elapsed(),
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Internal server error",
-                    &json!(null),
-                ));
-            };
+            writer.write(row)?;
         }
 
         writer.finalize()?;
@@ -93,12 +80,7 @@ pub async fn handler(
 
         let micros_total = dur.as_micros();
 
-        let micros_per_cell = if cell_count == 0 {
-            None
-        } else {
-            Some(micros_total / cell_count)
-        }
-        .unwrap_or_default();
+        let micros_per_cell = micros_total.checked_div(cell_count).unwrap_or_default();
 
         TableWriter::write_batch(
             table.metrics.clone(),
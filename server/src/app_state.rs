@@ -1,6 +1,6 @@
 use crate::{manifest::ManifestTable, metrics::MetricsTable};
 use fjall::{BlockCache, Keyspace};
-use smoltable::Smoltable;
+use smoltable::{SchemaMode, Smoltable};
 use std::{collections::HashMap, sync::Arc};
 use tokio::sync::RwLock;
 
@@ -27,15 +27,17 @@ pub struct AppState {
 }
 
 impl AppState {
-    // TODO: allow setting dedicated block cache per table
-    // TODO: if Some(...), show cache usage in table list PER table
-
-    pub async fn create_table(&self, table_name: &str) -> smoltable::Result<MonitoredSmoltable> {
+    pub async fn create_table(
+        &self,
+        table_name: &str,
+        schema_mode: SchemaMode,
+    ) -> smoltable::Result<MonitoredSmoltable> {
         let mut tables = self.tables.write().await;
 
         self.manifest_table.persist_user_table(table_name)?;
 
         let inner = Smoltable::open(table_name, self.keyspace.clone())?;
+        inner.set_schema_mode(schema_mode)?;
 
         let metrics =
             MetricsTable::open(self.keyspace.clone(), &format!("_mtx_{table_name}")).await?;
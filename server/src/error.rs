@@ -1,17 +1,72 @@
-use actix_web::{HttpResponse, ResponseError};
+use crate::response::build_response;
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use serde_json::json;
+use std::time::Duration;
 
 #[derive(Debug)]
 pub struct CustomHttpError(smoltable::Error);
 
 impl std::fmt::Display for CustomHttpError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.0)
+        write!(f, "{}", self.0)?;
+
+        let mut source = std::error::Error::source(&self.0);
+
+        while let Some(error) = source {
+            write!(f, ": {error}")?;
+            source = error.source();
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for CustomHttpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
     }
 }
 
 impl ResponseError for CustomHttpError {
+    fn status_code(&self) -> StatusCode {
+        use smoltable::Error::{
+            ColumnFamilyNotFound, InvalidColumnKey, InvalidPattern, InvalidValueType,
+            LocalityGroupNotFound, TableNotFound, UndefinedColumnFamilies, ValueTypeMismatch,
+        };
+
+        match &self.0 {
+            TableNotFound(_) | ColumnFamilyNotFound(_) | LocalityGroupNotFound(_) => {
+                StatusCode::NOT_FOUND
+            }
+            InvalidColumnKey(_)
+            | InvalidPattern(_)
+            | InvalidValueType(_)
+            | UndefinedColumnFamilies(_)
+            | ValueTypeMismatch(..) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
     fn error_response(&self) -> HttpResponse {
-        HttpResponse::InternalServerError().body("Internal Server Error")
+        build_response(
+            Duration::ZERO,
+            self.status_code(),
+            &self.client_message(),
+            &json!(null),
+        )
+    }
+}
+
+impl CustomHttpError {
+    /// Returns the error message that is sent to the client
+    pub fn client_message(&self) -> String {
+        // NOTE: Internal errors are only logged, to not leak internals to clients
+        if self.status_code().is_server_error() {
+            log::error!("Internal server error: {self}");
+            "Internal server error".to_owned()
+        } else {
+            self.0.to_string()
+        }
     }
 }
 
@@ -19,7 +19,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                     ColumnKey::try_from("value:").expect("should be valid column key"),
                 )),
                 cell_limit: Some(1_440 / 2),
+                ..Default::default()
             }),
+            ..Default::default()
         },
         Input {
             row: RowOptions {
@@ -31,7 +33,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                     ColumnKey::try_from("value:").expect("should be valid column key"),
                 )),
                 cell_limit: Some(1_440 / 2),
+                ..Default::default()
             }),
+            ..Default::default()
         },
         Input {
             row: RowOptions {
@@ -43,7 +47,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                     ColumnKey::try_from("value:").expect("should be valid column key"),
                 )),
                 cell_limit: Some(1_440 / 2),
+                ..Default::default()
             }),
+            ..Default::default()
         },
         Input {
             row: RowOptions {
@@ -55,7 +61,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                     ColumnKey::try_from("value:").expect("should be valid column key"),
                 )),
                 cell_limit: Some(1_440 / 2),
+                ..Default::default()
             }),
+            ..Default::default()
         },
     ])?;
 
@@ -80,7 +88,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -92,7 +102,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -104,7 +116,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -116,7 +130,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -128,7 +144,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -140,7 +158,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -152,7 +172,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -164,7 +186,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -176,7 +200,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
                 Input {
                     row: RowOptions {
@@ -188,7 +214,9 @@ pub async fn render_dashboard(data: web::Data<AppState>) -> CustomRouteResult<Ht
                             ColumnKey::try_from("value:").expect("should be valid column key"),
                         )),
                         cell_limit: Some(1_440 / 2),
+                        ..Default::default()
                     }),
+                    ..Default::default()
                 },
             ])?;
 
@@ -72,6 +72,7 @@ async fn main() -> smoltable::Result<()> {
                             ttl_secs: None,
                             version_limit: None,
                         },
+                        ..Default::default()
                     },
                     ColumnFamilyDefinition {
                         name: "stats".into(),
@@ -79,6 +80,7 @@ async fn main() -> smoltable::Result<()> {
                             ttl_secs: None,
                             version_limit: None,
                         },
+                        ..Default::default()
                     },
                     ColumnFamilyDefinition {
                         name: "lat".into(),
@@ -86,9 +88,11 @@ async fn main() -> smoltable::Result<()> {
                             ttl_secs: None,
                             version_limit: None,
                         },
+                        ..Default::default()
                     },
                 ],
                 locality_group: None,
+                ..Default::default()
             })?;
         }
 
@@ -135,11 +139,19 @@ async fn main() -> smoltable::Result<()> {
             .service(api::list_tables::handler)
             .service(api::create_table::handler)
             .service(api::write::handler)
+            .service(api::check_and_mutate::handler)
+            .service(api::read_modify_write::handler)
             .service(api::count::handler)
+            .service(api::aggregate::handler)
             .service(api::get_rows::handler)
             .service(api::delete_row::handler)
+            .service(api::delete_rows::handler)
             .service(api::scan::handler)
+            .service(api::scan_stream::handler)
             .service(api::create_column_family::handler)
+            .service(api::update_column_family::handler)
+            .service(api::delete_column_family::handler)
+            .service(api::regroup_column_families::handler)
             .service(api::metrics::handler)
             .service(api::delete_table::handler)
             .service(actix_files::Files::new("/", "./dist"))
@@ -1,7 +1,8 @@
 use crate::env::metrics_cap_mb;
 use fjall::Keyspace;
+use serde::Serialize;
 use smoltable::{
-    ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions, Smoltable,
+    Cell, ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions, Smoltable,
 };
 use std::sync::Arc;
 
@@ -29,8 +30,10 @@ impl MetricsTable {
                     ttl_secs: None,
                     version_limit: None,
                 },
+                ..Default::default()
             }],
             locality_group: None,
+            ..Default::default()
         })?;
 
         Ok(table)
@@ -43,3 +46,66 @@ macro_rules! data_point {
         smoltable::cell!("value", None, smoltable::CellValue::F64($value))
     };
 }
+
+/// Time series that are recorded in the metrics table of every user table
+pub const TABLE_METRIC_SERIES: &[&str] = &[
+    "lat#write#cell",
+    "lat#write#batch",
+    "lat#write#cond",
+    "lat#write#rmw",
+    "lat#read#pfx",
+    "lat#read#row",
+    "lat#del#row",
+    "lat#del#cell",
+    "stats#du",
+    "stats#seg_cnt",
+    "stats#row_cnt",
+    "stats#cell_cnt",
+    "gc#del_cnt",
+];
+
+#[derive(Debug, Serialize)]
+pub struct DataPoint {
+    pub time: u128,
+    pub value: f64,
+}
+
+/// Converts data points (newest first) into a time series (oldest first)
+///
+/// If a bucket size is given, data points are averaged per bucket,
+/// which is identified by its start time.
+pub fn downsample(cells: &[Cell], bucket_nanos: Option<u128>) -> Vec<DataPoint> {
+    let points = cells
+        .iter()
+        .rev()
+        .filter_map(|cell| cell.value.as_f64().map(|value| (cell.timestamp, value)));
+
+    let Some(bucket_nanos) = bucket_nanos.filter(|&x| x > 0) else {
+        return points
+            .map(|(time, value)| DataPoint { time, value })
+            .collect();
+    };
+
+    let mut series: Vec<DataPoint> = vec![];
+    let mut count: u32 = 0;
+
+    for (time, value) in points {
+        let bucket = time - time % bucket_nanos;
+
+        match series.last_mut() {
+            Some(point) if point.time == bucket => {
+                count += 1;
+                point.value += (value - point.value) / f64::from(count);
+            }
+            _ => {
+                count = 1;
+                series.push(DataPoint {
+                    time: bucket,
+                    value,
+                });
+            }
+        }
+    }
+
+    series
+}
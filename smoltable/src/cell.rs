@@ -1,6 +1,6 @@
-use crate::{column_key::ColumnKey, ColumnFilter};
+use crate::{column_key::ColumnKey, query::scan::TimeRange, ColumnFilter};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::{ops::Bound, sync::Arc};
 
 /// Cell value
 ///
@@ -37,9 +37,153 @@ pub enum Value {
     #[serde(rename = "f64")]
     /// floating point, 8 bytes
     F64(f64),
+
+    #[serde(rename = "bytes")]
+    /// raw byte array, base64 encoded in JSON
+    Bytes(#[serde(with = "base64_bytes")] Vec<u8>),
+
+    #[serde(rename = "u64")]
+    /// unsigned integer, 8 bytes
+    U64(u64),
+
+    #[serde(rename = "timestamp")]
+    /// milliseconds since Unix epoch, signed 8 bytes
+    Timestamp(i64),
+
+    #[serde(rename = "json")]
+    /// JSON document, stored as serialized JSON
+    Json(serde_json::Value),
+
+    #[serde(rename = "array")]
+    /// list of values of the same scalar type
+    Array(#[serde(deserialize_with = "deserialize_array")] Vec<Value>),
+}
+
+fn deserialize_array<'de, D>(deserializer: D) -> Result<Vec<Value>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let items = Vec::<Value>::deserialize(deserializer)?;
+
+    if has_nested_array(&items) {
+        return Err(serde::de::Error::custom("arrays cannot be nested"));
+    }
+
+    if !has_same_item_types(&items) {
+        return Err(serde::de::Error::custom(
+            "array items should have the same type",
+        ));
+    }
+
+    Ok(items)
+}
+
+fn has_nested_array(items: &[Value]) -> bool {
+    items.iter().any(|item| matches!(item, Value::Array(_)))
+}
+
+fn has_same_item_types(items: &[Value]) -> bool {
+    items.windows(2).all(|pair| pair[0].is_same_type(&pair[1]))
+}
+
+mod base64_bytes {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        STANDARD.decode(s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Type of a cell value, named like the `type` tag of [`Value`]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum ValueType {
+    #[serde(rename = "string")]
+    String,
+
+    #[serde(rename = "boolean")]
+    Boolean,
+
+    #[serde(rename = "byte")]
+    Byte,
+
+    #[serde(rename = "i32")]
+    I32,
+
+    #[serde(rename = "i64")]
+    I64,
+
+    #[serde(rename = "f32")]
+    F32,
+
+    #[serde(rename = "f64")]
+    F64,
+
+    #[serde(rename = "bytes")]
+    Bytes,
+
+    #[serde(rename = "u64")]
+    U64,
+
+    #[serde(rename = "timestamp")]
+    Timestamp,
+
+    #[serde(rename = "json")]
+    Json,
+
+    #[serde(rename = "array")]
+    Array,
+}
+
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = serde_json::to_value(self).expect("should serialize");
+        write!(f, "{}", name.as_str().expect("should be string"))
+    }
 }
 
 impl Value {
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Value::String(_) => ValueType::String,
+            Value::Boolean(_) => ValueType::Boolean,
+            Value::Byte(_) => ValueType::Byte,
+            Value::I32(_) => ValueType::I32,
+            Value::I64(_) => ValueType::I64,
+            Value::F32(_) => ValueType::F32,
+            Value::F64(_) => ValueType::F64,
+            Value::Bytes(_) => ValueType::Bytes,
+            Value::U64(_) => ValueType::U64,
+            Value::Timestamp(_) => ValueType::Timestamp,
+            Value::Json(_) => ValueType::Json,
+            Value::Array(_) => ValueType::Array,
+        }
+    }
+
+    /// Returns the value as a float, if it is numeric
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Byte(x) => Some(f64::from(*x)),
+            Value::I32(x) => Some(f64::from(*x)),
+            Value::I64(x) => Some(*x as f64),
+            Value::F32(x) => Some(f64::from(*x)),
+            Value::F64(x) => Some(*x),
+            Value::U64(x) => Some(*x as f64),
+            _ => None,
+        }
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         match self {
             Value::String(s) => {
@@ -72,71 +216,141 @@ impl Value {
                     bytes[7],
                 ]
             }
+            Value::Bytes(b) => {
+                let mut bytes = vec![0u8; 1 + b.len()];
+                bytes[0] = 7;
+                bytes[1..].copy_from_slice(b);
+                bytes
+            }
+            Value::U64(i) => {
+                let mut bytes = vec![8];
+                bytes.extend_from_slice(&i.to_be_bytes());
+                bytes
+            }
+            Value::Timestamp(ms) => {
+                let mut bytes = vec![9];
+                bytes.extend_from_slice(&ms.to_be_bytes());
+                bytes
+            }
+            Value::Json(json) => {
+                let mut bytes = vec![10];
+                serde_json::to_writer(&mut bytes, json).expect("should serialize JSON value");
+                bytes
+            }
+            Value::Array(items) => {
+                // NOTE: Items are length-prefixed, so they can be variable-sized
+                let mut bytes = vec![11];
+
+                for item in items {
+                    let item = item.to_bytes();
+                    bytes.extend_from_slice(&(item.len() as u32).to_be_bytes());
+                    bytes.extend_from_slice(&item);
+                }
+
+                bytes
+            }
         }
     }
 
-    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
-        if bytes.is_empty() {
-            return None;
-        }
+    /// Returns `true` if both values have the same type
+    pub fn is_same_type(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
 
-        match bytes[0] {
-            0 => {
-                let string_len = bytes.len() - 1;
-                let string_bytes = &bytes[1..];
+    /// Returns `false` if the value is an array that contains nested arrays
+    /// or items of different types
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Value::Array(items) => !has_nested_array(items) && has_same_item_types(items),
+            _ => true,
+        }
+    }
 
-                if string_len > 0 {
-                    let string =
-                        String::from_utf8(string_bytes.to_vec()).expect("should be utf-8 string");
+    /// Decodes a value, returning `None` if the bytes are not a valid encoded value
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (tag, rest) = bytes.split_first()?;
 
-                    Some(Value::String(string))
-                } else {
-                    Some(Value::String(String::new()))
-                }
-            }
-            1 => Some(Value::Boolean(bytes[1] != 0)),
-            2 => Some(Value::Byte(bytes[1])),
+        match tag {
+            0 => String::from_utf8(rest.to_vec()).ok().map(Value::String),
+            1 => Some(Value::Boolean(*rest.first()? != 0)),
+            2 => Some(Value::Byte(*rest.first()?)),
             3 => {
-                if bytes.len() < 5 {
-                    None
-                } else {
-                    let i = i32::from_be_bytes(bytes[1..5].try_into().expect("should deserialize"));
-                    Some(Value::I32(i))
-                }
+                let i = i32::from_be_bytes(rest.get(0..4)?.try_into().ok()?);
+                Some(Value::I32(i))
             }
             4 => {
-                if bytes.len() < 9 {
-                    None
-                } else {
-                    let i = i64::from_be_bytes(bytes[1..9].try_into().expect("should deserialize"));
-                    Some(Value::I64(i))
-                }
+                let i = i64::from_be_bytes(rest.get(0..8)?.try_into().ok()?);
+                Some(Value::I64(i))
             }
             5 => {
-                if bytes.len() < 5 {
-                    None
-                } else {
-                    let f = f32::from_be_bytes(bytes[1..5].try_into().expect("should deserialize"));
-                    Some(Value::F32(f))
-                }
+                let f = f32::from_be_bytes(rest.get(0..4)?.try_into().ok()?);
+                Some(Value::F32(f))
             }
             6 => {
-                if bytes.len() < 9 {
-                    None
-                } else {
-                    let f = f64::from_be_bytes(bytes[1..9].try_into().expect("should deserialize"));
-                    Some(Value::F64(f))
+                let f = f64::from_be_bytes(rest.get(0..8)?.try_into().ok()?);
+                Some(Value::F64(f))
+            }
+            7 => Some(Value::Bytes(rest.to_vec())),
+            8 => {
+                let i = u64::from_be_bytes(rest.get(0..8)?.try_into().ok()?);
+                Some(Value::U64(i))
+            }
+            9 => {
+                let ms = i64::from_be_bytes(rest.get(0..8)?.try_into().ok()?);
+                Some(Value::Timestamp(ms))
+            }
+            10 => serde_json::from_slice(rest).ok().map(Value::Json),
+            11 => {
+                let mut items = vec![];
+                let mut rest = rest;
+
+                while !rest.is_empty() {
+                    let len = u32::from_be_bytes(rest.get(0..4)?.try_into().ok()?) as usize;
+                    let item = rest.get(4..(4 + len))?;
+
+                    // NOTE: Arrays cannot be nested, so decoding never recurses more than once
+                    if item.first() == Some(&11) {
+                        return None;
+                    }
+
+                    items.push(Value::from_bytes(item)?);
+                    rest = &rest[(4 + len)..];
                 }
+
+                has_same_item_types(&items).then_some(Value::Array(items))
             }
             _ => None,
         }
     }
 }
 
+/// Values are only comparable to values of the same type
+///
+/// JSON documents are not ordered, they can only be equal.
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self, other) {
+            (Value::String(a), Value::String(b)) => a.partial_cmp(b),
+            (Value::Boolean(a), Value::Boolean(b)) => a.partial_cmp(b),
+            (Value::Byte(a), Value::Byte(b)) => a.partial_cmp(b),
+            (Value::I32(a), Value::I32(b)) => a.partial_cmp(b),
+            (Value::I64(a), Value::I64(b)) => a.partial_cmp(b),
+            (Value::F32(a), Value::F32(b)) => a.partial_cmp(b),
+            (Value::F64(a), Value::F64(b)) => a.partial_cmp(b),
+            (Value::Bytes(a), Value::Bytes(b)) => a.partial_cmp(b),
+            (Value::U64(a), Value::U64(b)) => a.partial_cmp(b),
+            (Value::Timestamp(a), Value::Timestamp(b)) => a.partial_cmp(b),
+            (Value::Json(a), Value::Json(b)) => (a == b).then_some(std::cmp::Ordering::Equal),
+            (Value::Array(a), Value::Array(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
 /// A cell and its meta information visited by an iterator
 #[derive(Clone, Debug)]
 pub struct VisitedCell {
-    /// The raw cell key, which is `row_key:cf:cq:!ts`
+    /// The raw cell key, which is `row_key cf cq !ts` (escaped & terminated components)
     pub raw_key: Arc<[u8]>,
 
     /// User row key
@@ -166,14 +380,16 @@ impl std::fmt::Display for VisitedCell {
 
 impl VisitedCell {
     pub fn format_key(row_key: &str, column_key: &ColumnKey, timestamp: u128) -> Vec<u8> {
-        let mut key = format!(
-            "{}:{}:{}:",
-            row_key,
-            column_key.family,
-            column_key.qualifier.as_ref().cloned().unwrap_or_default(),
-        )
-        .as_bytes()
-        .to_vec();
+        let mut key = crate::key::row_prefix(row_key);
+        crate::key::push_component(&mut key, column_key.family.as_bytes());
+        crate::key::push_component(
+            &mut key,
+            column_key
+                .qualifier
+                .as_deref()
+                .unwrap_or_default()
+                .as_bytes(),
+        );
 
         // NOTE: Reverse the timestamp to store it in descending order
         key.extend_from_slice(&(!timestamp).to_be_bytes());
@@ -181,33 +397,69 @@ impl VisitedCell {
         key
     }
 
-    pub fn parse(key: Arc<[u8]>, value: &[u8]) -> VisitedCell {
-        let mut buf = [0; std::mem::size_of::<u128>()];
-        buf.clone_from_slice(&key[(key.len() - std::mem::size_of::<u128>())..key.len()]);
-        let ts = !u128::from_be_bytes(buf);
+    /// Formats a key that is greater than every cell key of the row
+    pub fn format_row_end_key(row_key: &str) -> Vec<u8> {
+        crate::key::row_end(row_key)
+    }
+
+    /// Decodes a cell from its raw key and value
+    ///
+    /// Returns [`Error::Decode`](crate::Error::Decode) if the cell is corrupt.
+    pub fn parse(key: Arc<[u8]>, value: &[u8]) -> crate::Result<VisitedCell> {
+        Self::try_parse(&key, value).ok_or_else(|| crate::Error::Decode(key.clone()))
+    }
 
-        // NOTE: -1 because of : delimiter
-        let key_without_ts = &key[0..(key.len() - std::mem::size_of::<u128>() - 1)];
-        let mut parsed_key = key_without_ts.rsplitn(3, |&e| e == b':');
+    fn try_parse(key: &Arc<[u8]>, value: &[u8]) -> Option<VisitedCell> {
+        let ts_start = key.len().checked_sub(std::mem::size_of::<u128>())?;
+        let (key_without_ts, ts) = key.split_at(ts_start);
 
-        let last = parsed_key.next().unwrap();
-        let cq = std::str::from_utf8(last).ok().map(Into::into);
+        let ts = !u128::from_be_bytes(ts.try_into().ok()?);
 
-        let last = parsed_key.next().unwrap();
-        let cf = std::str::from_utf8(last).unwrap();
+        let (row_key, rest) = crate::key::split_component(key_without_ts)?;
+        let (cf, rest) = crate::key::split_component(rest)?;
+        let (cq, rest) = crate::key::split_component(rest)?;
 
-        let last = parsed_key.next().unwrap();
-        let row_key = std::str::from_utf8(last).unwrap();
+        if !rest.is_empty() {
+            return None;
+        }
 
-        VisitedCell {
+        Some(VisitedCell {
             raw_key: key.clone(),
-            row_key: row_key.to_owned(),
+            row_key: String::from_utf8(row_key).ok()?,
             timestamp: ts,
             column_key: ColumnKey {
-                family: cf.to_owned(),
-                qualifier: cq,
+                family: String::from_utf8(cf).ok()?,
+                qualifier: Some(String::from_utf8(cq).ok()?),
             },
-            value: Value::from_bytes(value).expect("should deserialize"),
+            value: Value::from_bytes(value)?,
+        })
+    }
+
+    /// Returns the key bound to continue reading from, if the cell is outside the time range
+    ///
+    /// Versions are stored with negated timestamps, so a column's versions are
+    /// sorted from newest to oldest. Too new versions can be skipped by seeking
+    /// to the end of the time range, too old versions by seeking past the column.
+    pub(crate) fn seek_key_for_time_range(&self, range: &TimeRange) -> Option<Bound<Arc<[u8]>>> {
+        let is_too_new = range.end.is_some_and(|end| self.timestamp >= end);
+        let is_too_old = range.start.is_some_and(|start| self.timestamp < start);
+
+        if !is_too_new && !is_too_old {
+            return None;
+        }
+
+        let mut key = self.raw_key[0..(self.raw_key.len() - std::mem::size_of::<u128>())].to_vec();
+
+        match range.end {
+            Some(end) if is_too_new && end > 0 => {
+                key.extend_from_slice(&(!(end - 1)).to_be_bytes());
+                Some(Bound::Included(key.into()))
+            }
+            _ => {
+                // NOTE: The negated timestamp 0 is the last possible key of the column
+                key.extend_from_slice(&(!0_u128).to_be_bytes());
+                Some(Bound::Excluded(key.into()))
+            }
         }
     }
 
@@ -262,6 +514,13 @@ impl VisitedCell {
 
                 true
             }
+            ColumnFilter::Range(range) => {
+                if self.column_key.family != range.family {
+                    return false;
+                }
+
+                range.contains(self.column_key.qualifier.as_deref().unwrap_or_default())
+            }
         }
     }
 }
@@ -286,12 +545,37 @@ mod tests {
     fn cell_format_key() {
         let key = VisitedCell::format_key("test", &ColumnKey::try_from("value:").unwrap(), 0);
 
-        let cell = VisitedCell::parse(key.clone().into(), &CellValue::Byte(0).to_bytes());
+        let cell = VisitedCell::parse(key.clone().into(), &CellValue::Byte(0).to_bytes()).unwrap();
 
         assert_eq!(cell.raw_key, key.into());
         assert_eq!(cell.value, CellValue::Byte(0));
     }
 
+    #[test]
+    fn cell_format_key_delimiters() {
+        let column_key = ColumnKey {
+            family: "value".into(),
+            qualifier: Some("a:b\0c:".into()),
+        };
+        let key = VisitedCell::format_key("user:1:\0", &column_key, 5);
+
+        let cell = VisitedCell::parse(key.into(), &CellValue::Byte(0).to_bytes()).unwrap();
+
+        assert_eq!("user:1:\0", cell.row_key);
+        assert_eq!(column_key, cell.column_key);
+        assert_eq!(5, cell.timestamp);
+    }
+
+    #[test]
+    fn cell_value_type() {
+        assert_eq!(ValueType::F64, Value::F64(1.0).value_type());
+        assert_eq!(ValueType::Array, Value::Array(vec![]).value_type());
+        assert_eq!("timestamp", ValueType::Timestamp.to_string());
+
+        let value_type: ValueType = serde_json::from_str("\"string\"").unwrap();
+        assert_eq!(ValueType::String, value_type);
+    }
+
     #[test]
     fn cell_serde() {
         let cell = Cell {
@@ -312,4 +596,151 @@ mod tests {
             .unwrap()
         );
     }
+
+    #[test]
+    fn cell_serde_bytes() {
+        let cell = Cell {
+            timestamp: 0,
+            value: Value::Bytes(vec![0, 1, 2, 255]),
+        };
+
+        let s = serde_json::to_string(&cell).unwrap();
+        assert_eq!(r#"{"time":0,"type":"bytes","value":"AAEC/w=="}"#, s);
+
+        let p: Cell = serde_json::from_str(&s).unwrap();
+        assert_eq!(cell, p);
+
+        assert!(serde_json::from_str::<Cell>(r#"{"time":0,"type":"bytes","value":"!"}"#).is_err());
+    }
+
+    #[test]
+    fn cell_value_bytes_encoding() {
+        for bytes in [vec![], vec![0, 1, 2, 255]] {
+            let value = Value::Bytes(bytes);
+            assert_eq!(Some(value.clone()), Value::from_bytes(&value.to_bytes()));
+        }
+    }
+
+    #[test]
+    fn cell_serde_rich_types() {
+        for (value, json) in [
+            (
+                Value::U64(u64::MAX),
+                r#"{"type":"u64","value":18446744073709551615}"#,
+            ),
+            (
+                Value::Timestamp(1_706_799_120_912),
+                r#"{"type":"timestamp","value":1706799120912}"#,
+            ),
+            (
+                Value::Json(serde_json::json!({ "a": [1, "b", null] })),
+                r#"{"type":"json","value":{"a":[1,"b",null]}}"#,
+            ),
+            (
+                Value::Array(vec![Value::String("a".into()), Value::String("b".into())]),
+                r#"{"type":"array","value":[{"type":"string","value":"a"},{"type":"string","value":"b"}]}"#,
+            ),
+        ] {
+            assert_eq!(json, serde_json::to_string(&value).unwrap());
+
+            let p: Value = serde_json::from_str(json).unwrap();
+            assert_eq!(value, p);
+
+            assert_eq!(Some(value.clone()), Value::from_bytes(&value.to_bytes()));
+        }
+    }
+
+    #[test]
+    fn cell_serde_array_homogeneous() {
+        assert!(serde_json::from_str::<Value>(
+            r#"{"type":"array","value":[{"type":"string","value":"a"},{"type":"i32","value":1}]}"#
+        )
+        .is_err());
+
+        assert!(serde_json::from_str::<Value>(
+            r#"{"type":"array","value":[{"type":"array","value":[]}]}"#
+        )
+        .is_err());
+
+        let value = Value::Array(vec![]);
+        assert_eq!(Some(value.clone()), Value::from_bytes(&value.to_bytes()));
+    }
+
+    #[test]
+    fn cell_value_compare_type_aware() {
+        use std::cmp::Ordering;
+
+        assert_eq!(
+            Some(Ordering::Less),
+            Value::U64(1).partial_cmp(&Value::U64(2))
+        );
+        assert_eq!(None, Value::U64(1).partial_cmp(&Value::I64(2)));
+        assert_eq!(
+            Some(Ordering::Greater),
+            Value::Timestamp(2).partial_cmp(&Value::Timestamp(-1))
+        );
+
+        let json = Value::Json(serde_json::json!({ "a": 1 }));
+        assert_eq!(Some(Ordering::Equal), json.partial_cmp(&json.clone()));
+        assert_eq!(
+            None,
+            json.partial_cmp(&Value::Json(serde_json::json!({ "a": 2 })))
+        );
+
+        assert_eq!(
+            Some(Ordering::Less),
+            Value::Array(vec![Value::Byte(1)])
+                .partial_cmp(&Value::Array(vec![Value::Byte(1), Value::Byte(0)]))
+        );
+    }
+
+    #[test]
+    fn cell_value_decode_corrupt() {
+        for bytes in [
+            &[][..],
+            &[0, 0xFF],
+            &[1],
+            &[2],
+            &[3, 0, 0],
+            &[4, 0],
+            &[10, b'{'],
+            &[11, 0, 0, 0, 5, 2],
+            &[11, 0, 0, 0, 1, 11],
+            &[11, 0, 0, 0, 2, 2, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0],
+            &[99],
+        ] {
+            assert_eq!(None, Value::from_bytes(bytes));
+        }
+
+        // NOTE: Deeply nested arrays are rejected without overflowing the stack
+        let depth = 100_000;
+        let mut nested = vec![11];
+
+        for level in 0..depth {
+            let len = 5 * (depth - 1 - level) + 1;
+            nested.extend_from_slice(&(len as u32).to_be_bytes());
+            nested.push(11);
+        }
+
+        assert_eq!(None, Value::from_bytes(&nested));
+    }
+
+    #[test]
+    fn cell_parse_corrupt() {
+        let value = CellValue::Byte(0).to_bytes();
+
+        for key in [&b""[..], b"a:b:c:", &[0xFF; 20]] {
+            assert!(matches!(
+                VisitedCell::parse(key.into(), &value),
+                Err(crate::Error::Decode(_))
+            ));
+        }
+
+        let key = VisitedCell::format_key("test", &ColumnKey::try_from("value:").unwrap(), 0);
+
+        assert!(matches!(
+            VisitedCell::parse(key.into(), &[99]),
+            Err(crate::Error::Decode(_))
+        ));
+    }
 }
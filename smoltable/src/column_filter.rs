@@ -16,4 +16,48 @@ pub enum ColumnFilter {
 
     #[serde(rename = "prefix")]
     Prefix(ColumnKey),
+
+    #[serde(rename = "range")]
+    Range(ColumnRange),
+}
+
+/// A range of column qualifiers inside a single column family
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ColumnRange {
+    pub family: String,
+    pub start: String,
+    pub end: String,
+    pub inclusive: bool,
+}
+
+impl ColumnRange {
+    /// Returns `true` if the qualifier is inside the range
+    pub fn contains(&self, qualifier: &str) -> bool {
+        if qualifier < self.start.as_str() {
+            return false;
+        }
+
+        if self.inclusive {
+            qualifier <= self.end.as_str()
+        } else {
+            qualifier < self.end.as_str()
+        }
+    }
+
+    /// Returns `true` if the qualifier sorts after the end of the range
+    pub fn is_past_end(&self, qualifier: &str) -> bool {
+        if self.inclusive {
+            qualifier > self.end.as_str()
+        } else {
+            qualifier >= self.end.as_str()
+        }
+    }
+
+    /// Builds the key of the first possible cell of the range in the given row
+    pub(crate) fn build_start_key(&self, row_key: &str) -> Vec<u8> {
+        let mut key = crate::key::row_prefix(row_key);
+        crate::key::push_component(&mut key, self.family.as_bytes());
+        crate::key::escape_into(&mut key, self.start.as_bytes());
+        key
+    }
 }
@@ -15,6 +15,9 @@ pub fn is_valid_identifier(s: &str) -> bool {
 /// It is defined as "family:qualifier", where qualifier may be empty
 /// (the colon may omitted in that case).
 ///
+/// Only the first colon separates the family from the qualifier,
+/// so the qualifier may contain colons itself.
+///
 /// A column family may house arbitrarily many columns.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ColumnKey {
@@ -23,11 +26,16 @@ pub struct ColumnKey {
 }
 
 impl ColumnKey {
-    pub fn build_key(&self, row_key: &str) -> String {
-        match &self.qualifier {
-            Some(cq) => format!("{row_key}:{}:{cq}:", self.family),
-            None => format!("{row_key}:{}:", self.family),
+    /// Builds the key prefix of all cells of the column (or column family) in the given row
+    pub fn build_key(&self, row_key: &str) -> Vec<u8> {
+        let mut key = crate::key::row_prefix(row_key);
+        crate::key::push_component(&mut key, self.family.as_bytes());
+
+        if let Some(cq) = &self.qualifier {
+            crate::key::push_component(&mut key, cq.as_bytes());
         }
+
+        key
     }
 }
 
@@ -43,10 +51,10 @@ impl std::fmt::Display for ColumnKey {
 }
 
 impl TryFrom<&str> for ColumnKey {
-    type Error = ();
+    type Error = crate::Error;
 
-    fn try_from(value: &str) -> Result<Self, ()> {
-        let mut splits = value.split(':');
+    fn try_from(value: &str) -> crate::Result<Self> {
+        let mut splits = value.splitn(2, ':');
 
         let family = splits.next();
         let qualifier = splits.next();
@@ -58,7 +66,7 @@ impl TryFrom<&str> for ColumnKey {
             }),
             (Some(family), Some(qualifier)) => {
                 if !is_valid_identifier(family) {
-                    return Err(());
+                    return Err(crate::Error::InvalidColumnKey(value.to_owned()));
                 }
 
                 Ok(Self {
@@ -66,7 +74,7 @@ impl TryFrom<&str> for ColumnKey {
                     qualifier: Some(qualifier.to_owned()),
                 })
             }
-            _ => Err(()),
+            _ => Err(crate::Error::InvalidColumnKey(value.to_owned())),
         }
     }
 }
@@ -125,4 +133,16 @@ mod tests {
             ColumnKey::try_from("test:abc").unwrap().to_string()
         );
     }
+
+    #[test]
+    fn parse_column_key_with_colons() {
+        let column_key = ColumnKey::try_from("cf:user:123").unwrap();
+        assert_eq!("cf", column_key.family);
+        assert_eq!(Some("user:123"), column_key.qualifier.as_deref());
+        assert_eq!("cf:user:123", column_key.to_string());
+
+        let column_key: ColumnKey = serde_json::from_str("\"cf::\"").unwrap();
+        assert_eq!("cf", column_key.family);
+        assert_eq!(Some(":"), column_key.qualifier.as_deref());
+    }
 }
@@ -3,15 +3,79 @@ pub enum Error {
     Storage(fjall::Error),
     Tree(fjall::LsmError),
     Io(std::io::Error),
+
+    /// The table does not exist
+    TableNotFound(String),
+
+    /// The column family is not defined in the table
+    ColumnFamilyNotFound(String),
+
+    /// The locality group does not exist in the table
+    LocalityGroupNotFound(String),
+
+    /// Cells were written to column families that are not defined in the table
+    UndefinedColumnFamilies(Vec<String>),
+
+    /// The string is not a valid column key (`family:qualifier`)
+    InvalidColumnKey(String),
+
+    /// The string is not a valid regular expression
+    InvalidPattern(String),
+
+    /// A cell could not be decoded, contains the raw key of the corrupt cell
+    Decode(std::sync::Arc<[u8]>),
+
+    /// The cell value type does not support the requested operation
+    InvalidValueType(crate::ColumnKey),
+
+    /// The cell value does not have the value type defined for its column
+    ValueTypeMismatch(crate::ColumnKey, crate::CellValueType),
+
+    /// The table was written in a newer on-disk format than supported
+    UnsupportedFormatVersion(u32),
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "SmoltableError")
+        match self {
+            Self::Storage(_) => write!(f, "storage error"),
+            Self::Tree(_) => write!(f, "LSM-tree error"),
+            Self::Io(_) => write!(f, "I/O error"),
+            Self::TableNotFound(name) => write!(f, "table {name:?} not found"),
+            Self::ColumnFamilyNotFound(name) => write!(f, "column family {name:?} not found"),
+            Self::LocalityGroupNotFound(id) => write!(f, "locality group {id:?} not found"),
+            Self::UndefinedColumnFamilies(names) => {
+                write!(f, "undefined column families: {}", names.join(", "))
+            }
+            Self::InvalidColumnKey(key) => write!(f, "invalid column key {key:?}"),
+            Self::InvalidPattern(pattern) => write!(f, "invalid regular expression {pattern:?}"),
+            Self::Decode(key) => write!(f, "corrupt cell with key {key:?}"),
+            Self::InvalidValueType(column_key) => {
+                write!(f, "column {column_key} has an invalid value type")
+            }
+            Self::ValueTypeMismatch(column_key, value_type) => {
+                write!(
+                    f,
+                    "column {column_key} only accepts values of type {value_type}"
+                )
+            }
+            Self::UnsupportedFormatVersion(version) => {
+                write!(f, "unsupported on-disk format version {version}")
+            }
+        }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Storage(e) => Some(e),
+            Self::Tree(e) => Some(e),
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<fjall::Error> for Error {
     fn from(value: fjall::Error) -> Self {
@@ -32,3 +96,33 @@ impl From<fjall::LsmError> for Error {
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+    use test_log::test;
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            "column family \"abc\" not found",
+            Error::ColumnFamilyNotFound("abc".into()).to_string()
+        );
+
+        assert_eq!(
+            "invalid column key \"a!:b\"",
+            Error::InvalidColumnKey("a!:b".into()).to_string()
+        );
+    }
+
+    #[test]
+    fn error_source() {
+        let error = Error::from(std::io::Error::other("disk on fire"));
+
+        assert_eq!("I/O error", error.to_string());
+        assert_eq!("disk on fire", error.source().unwrap().to_string());
+
+        assert!(Error::TableNotFound("abc".into()).source().is_none());
+    }
+}
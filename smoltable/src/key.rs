@@ -0,0 +1,158 @@
+//! Binary-safe encoding of cell keys
+//!
+//! A cell key is stored as `row_key cf cq !ts`, where every component
+//! is escaped and terminated:
+//!
+//! - `0x00` is escaped as `0x00 0xFF`
+//! - every component is terminated by `0x00 0x01`
+//!
+//! The encoding is prefix-free and preserves the byte order of the components,
+//! so row keys and qualifiers may contain any bytes (including ':')
+//! without bleeding into neighbouring rows or columns.
+
+/// Current on-disk key format version
+///
+/// - 1: `row_key:cf:cq:!ts` (':' delimited, not binary-safe)
+/// - 2: escaped & terminated components
+pub const FORMAT_VERSION: u32 = 2;
+
+const ESCAPE: u8 = 0x00;
+const ESCAPED_NULL: u8 = 0xFF;
+const TERMINATOR: u8 = 0x01;
+
+/// Marks the end of all cell keys of a row, greater than any terminator
+const ROW_END: u8 = 0x02;
+
+/// Appends the escaped bytes, without terminating the component
+///
+/// Escaping is order-preserving and keeps prefixes intact, so it
+/// can be used to build prefix and range bounds.
+pub fn escape_into(key: &mut Vec<u8>, bytes: &[u8]) {
+    for &byte in bytes {
+        if byte == ESCAPE {
+            key.extend_from_slice(&[ESCAPE, ESCAPED_NULL]);
+        } else {
+            key.push(byte);
+        }
+    }
+}
+
+/// Returns the escaped bytes, without terminating the component
+pub fn escape(bytes: &[u8]) -> Vec<u8> {
+    let mut key = Vec::with_capacity(bytes.len());
+    escape_into(&mut key, bytes);
+    key
+}
+
+/// Appends an escaped & terminated component
+pub fn push_component(key: &mut Vec<u8>, bytes: &[u8]) {
+    escape_into(key, bytes);
+    key.extend_from_slice(&[ESCAPE, TERMINATOR]);
+}
+
+/// Formats the prefix that all cell keys of the row share
+pub fn row_prefix(row_key: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(row_key.len() + 2);
+    push_component(&mut key, row_key.as_bytes());
+    key
+}
+
+/// Formats a key that is greater than every cell key of the row,
+/// but less than the cell keys of any following row
+pub fn row_end(row_key: &str) -> Vec<u8> {
+    let mut key = escape(row_key.as_bytes());
+    key.extend_from_slice(&[ESCAPE, ROW_END]);
+    key
+}
+
+/// Splits off the first component, returning it (unescaped) and the remaining key
+///
+/// Returns `None` if the key is not correctly encoded.
+pub fn split_component(key: &[u8]) -> Option<(Vec<u8>, &[u8])> {
+    let mut component = Vec::new();
+    let mut idx = 0;
+
+    loop {
+        let byte = *key.get(idx)?;
+
+        if byte == ESCAPE {
+            match *key.get(idx + 1)? {
+                ESCAPED_NULL => component.push(ESCAPE),
+                TERMINATOR => return Some((component, &key[(idx + 2)..])),
+                _ => return None,
+            }
+
+            idx += 2;
+        } else {
+            component.push(byte);
+            idx += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn encode(components: &[&[u8]]) -> Vec<u8> {
+        let mut key = vec![];
+
+        for component in components {
+            push_component(&mut key, component);
+        }
+
+        key
+    }
+
+    #[test]
+    fn key_roundtrip() {
+        let key = encode(&[b"a:b\0c", b"", b"\0\0:"]);
+
+        let (a, rest) = split_component(&key).unwrap();
+        let (b, rest) = split_component(rest).unwrap();
+        let (c, rest) = split_component(rest).unwrap();
+
+        assert_eq!(b"a:b\0c", &*a);
+        assert_eq!(b"", &*b);
+        assert_eq!(b"\0\0:", &*c);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn key_invalid() {
+        assert!(split_component(b"abc").is_none());
+        assert!(split_component(b"abc\0").is_none());
+        assert!(split_component(b"abc\0\x05").is_none());
+    }
+
+    #[test]
+    fn key_order_preserving() {
+        let mut items: Vec<&[u8]> =
+            vec![b"", b"\0", b"\0\0", b"\x01", b"a", b"a\0", b"a\x01", b"ab"];
+        items.sort();
+
+        let encoded = items
+            .iter()
+            .map(|item| encode(&[item, b"cf"]))
+            .collect::<Vec<_>>();
+
+        let mut sorted = encoded.clone();
+        sorted.sort();
+
+        assert_eq!(encoded, sorted);
+    }
+
+    #[test]
+    fn key_row_prefix_no_bleed() {
+        let row_1 = encode(&[b"user:1", b"cf"]);
+        let row_12 = encode(&[b"user:12", b"cf"]);
+
+        assert!(row_1.starts_with(&row_prefix("user:1")));
+        assert!(!row_12.starts_with(&row_prefix("user:1")));
+
+        assert!(row_1 < row_end("user:1"));
+        assert!(row_end("user:1") < row_12);
+        assert!(row_end("user:1") < encode(&[b"user:1\0", b"cf"]));
+    }
+}
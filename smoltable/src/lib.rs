@@ -2,6 +2,7 @@ mod cell;
 mod column_filter;
 mod column_key;
 mod error;
+mod key;
 pub mod query;
 mod row;
 mod table;
@@ -9,14 +10,18 @@ mod table;
 pub use {
     cell::Cell,
     cell::Value as CellValue,
+    cell::ValueType as CellValueType,
     cell::VisitedCell,
-    column_filter::ColumnFilter,
+    column_filter::{ColumnFilter, ColumnRange},
     column_key::ColumnKey,
     error::{Error, Result},
+    query::filter::RowFilter,
     row::Row,
-    table::writer::{ColumnWriteItem, RowWriteItem, Writer as TableWriter},
+    table::writer::{ColumnWriteItem, DeleteWriteItem, RowWriteItem, Writer as TableWriter},
     table::{
-        ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions, Smoltable,
+        CellIterator, ColumnFamilyDefinition, CompactionOptions, CreateColumnFamilyInput,
+        GarbageCollectionOptions, LocalityGroupOptions, LocalityGroupTarget,
+        RegroupColumnFamiliesInput, RowIterator, SchemaMode, Smoltable, ValueTypeDefinition,
         BLOCK_SIZE,
     },
 };
@@ -27,6 +32,7 @@ macro_rules! row {
         $crate::RowWriteItem {
             row_key: $key.to_string(),
             cells: $cells,
+            deletes: vec![],
         }
     };
 }
@@ -0,0 +1,201 @@
+use super::{
+    filter::RowFilter,
+    scan::{ScanMode, TimeRange},
+};
+use crate::{Cell, CellValue, ColumnFilter};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Groups rows by a part of their row key
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum GroupBy {
+    /// The first N characters of the row key
+    #[serde(rename = "prefix_length")]
+    PrefixLength(usize),
+
+    /// The row key up to and including the first delimiter after the scan prefix
+    ///
+    /// Row keys without a delimiter form their own group.
+    #[serde(rename = "delimiter")]
+    Delimiter(String),
+}
+
+impl GroupBy {
+    /// Returns the group key of a row
+    pub fn group_key<'a>(&self, row_key: &'a str, scan: &ScanMode) -> &'a str {
+        match self {
+            GroupBy::PrefixLength(len) => row_key
+                .char_indices()
+                .nth(*len)
+                .map_or(row_key, |(idx, _)| &row_key[..idx]),
+            GroupBy::Delimiter(delimiter) => {
+                let offset = match scan {
+                    ScanMode::Prefix(prefix) if row_key.starts_with(prefix.as_str()) => {
+                        prefix.len()
+                    }
+                    _ => 0,
+                };
+
+                row_key[offset..]
+                    .find(delimiter.as_str())
+                    .map_or(row_key, |idx| &row_key[..offset + idx + delimiter.len()])
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RowOptions {
+    #[serde(flatten)]
+    pub scan: ScanMode,
+
+    /// If not set, all rows are aggregated into one group
+    pub group_by: Option<GroupBy>,
+
+    /// Maximum number of returned groups
+    ///
+    /// Groups are visited in row key order, so the scan stops once the limit is reached.
+    pub group_limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ColumnOptions {
+    #[serde(flatten)]
+    pub filter: Option<ColumnFilter>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CellOptions {
+    pub time: Option<TimeRange>,
+
+    /// Aggregates every version of a column, instead of only its latest version
+    #[serde(default)]
+    pub all_versions: bool,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Input {
+    pub row: RowOptions,
+    pub column: Option<ColumnOptions>,
+    pub cell: Option<CellOptions>,
+
+    /// Composable filter that every aggregated cell has to match
+    pub filter: Option<RowFilter>,
+}
+
+/// Aggregates of the numeric cell values of a column
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ColumnAggregate {
+    pub count: u64,
+    pub sum: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub avg: Option<f64>,
+
+    /// Cell with the lowest timestamp
+    pub first: Option<Cell>,
+
+    /// Cell with the highest timestamp
+    pub last: Option<Cell>,
+}
+
+impl ColumnAggregate {
+    /// Adds a cell to the aggregate, returning `false` if its value is not numeric
+    pub fn push(&mut self, timestamp: u128, value: CellValue) -> bool {
+        let Some(number) = value.as_f64() else {
+            return false;
+        };
+
+        self.count += 1;
+        self.sum += number;
+        self.min = Some(self.min.map_or(number, |min| min.min(number)));
+        self.max = Some(self.max.map_or(number, |max| max.max(number)));
+        self.avg = Some(self.sum / self.count as f64);
+
+        if self
+            .last
+            .as_ref()
+            .map_or(true, |last| timestamp > last.timestamp)
+        {
+            self.last = Some(Cell {
+                timestamp,
+                value: value.clone(),
+            });
+        }
+
+        if self
+            .first
+            .as_ref()
+            .map_or(true, |first| timestamp < first.timestamp)
+        {
+            self.first = Some(Cell { timestamp, value });
+        }
+
+        true
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Group {
+    /// Group key, empty if rows are not grouped
+    pub key: String,
+
+    /// Amount of rows with at least one aggregated cell
+    pub row_count: u64,
+
+    /// Aggregates per column key
+    pub columns: BTreeMap<String, ColumnAggregate>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Output {
+    pub affected_locality_groups: usize,
+    pub groups: Vec<Group>,
+    pub cell_count: u64,
+    pub row_count: u64,
+    pub bytes_scanned_count: u64,
+
+    /// Set if the group limit was reached, so rows after the last group were not aggregated
+    pub is_group_limit_reached: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn aggregate_group_key() {
+        let scan = ScanMode::Prefix("sensor#".into());
+
+        let group_by = GroupBy::PrefixLength(3);
+        assert_eq!("sen", group_by.group_key("sensor#a", &scan));
+        assert_eq!("sé", group_by.group_key("sé", &scan));
+
+        let group_by = GroupBy::Delimiter("#".into());
+        assert_eq!("sensor#a#", group_by.group_key("sensor#a#1", &scan));
+        assert_eq!("sensor#b", group_by.group_key("sensor#b", &scan));
+        assert_eq!(
+            "sensor#",
+            group_by.group_key("sensor#a", &ScanMode::Prefix(String::new()))
+        );
+    }
+
+    #[test]
+    fn aggregate_column() {
+        let mut aggregate = ColumnAggregate::default();
+
+        assert!(aggregate.push(2, CellValue::I32(4)));
+        assert!(aggregate.push(1, CellValue::F64(1.0)));
+        assert!(aggregate.push(3, CellValue::U64(7)));
+        assert!(!aggregate.push(4, CellValue::String("abc".into())));
+
+        assert_eq!(3, aggregate.count);
+        assert_eq!(12.0, aggregate.sum);
+        assert_eq!(Some(1.0), aggregate.min);
+        assert_eq!(Some(7.0), aggregate.max);
+        assert_eq!(Some(4.0), aggregate.avg);
+        assert_eq!(Some(CellValue::F64(1.0)), aggregate.first.map(|x| x.value));
+        assert_eq!(Some(CellValue::U64(7)), aggregate.last.map(|x| x.value));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{CellValue, ColumnKey, ColumnWriteItem, DeleteWriteItem};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub enum CompareOp {
+    #[serde(rename = "eq")]
+    Equal,
+
+    #[serde(rename = "ne")]
+    NotEqual,
+
+    #[serde(rename = "lt")]
+    Less,
+
+    #[serde(rename = "lte")]
+    LessOrEqual,
+
+    #[serde(rename = "gt")]
+    Greater,
+
+    #[serde(rename = "gte")]
+    GreaterOrEqual,
+}
+
+impl CompareOp {
+    /// Returns `true` if the cell value compared to the expected value satisfies the operator
+    ///
+    /// Values of different types never match. Values that have no order
+    /// (e.g. JSON documents) are only ordered if they are equal.
+    pub fn matches(self, value: &CellValue, expected: &CellValue) -> bool {
+        if !value.is_same_type(expected) {
+            return false;
+        }
+
+        let is_ordering = |f: fn(Ordering) -> bool| value.partial_cmp(expected).is_some_and(f);
+
+        match self {
+            CompareOp::Equal => value == expected,
+            CompareOp::NotEqual => value != expected,
+            CompareOp::Less => is_ordering(Ordering::is_lt),
+            CompareOp::LessOrEqual => is_ordering(Ordering::is_le),
+            CompareOp::Greater => is_ordering(Ordering::is_gt),
+            CompareOp::GreaterOrEqual => is_ordering(Ordering::is_ge),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Comparison {
+    pub column_key: ColumnKey,
+    pub op: CompareOp,
+
+    #[serde(flatten)]
+    pub value: CellValue,
+}
+
+/// Predicate that is evaluated against the latest version of a column
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Predicate {
+    /// The column has at least one version
+    #[serde(rename = "exists")]
+    Exists(ColumnKey),
+
+    /// The latest value of the column compares to the given value
+    ///
+    /// Values of different types never match.
+    #[serde(rename = "compare")]
+    Compare(Comparison),
+}
+
+impl Predicate {
+    pub fn column_key(&self) -> &ColumnKey {
+        match self {
+            Predicate::Exists(column_key) => column_key,
+            Predicate::Compare(comparison) => &comparison.column_key,
+        }
+    }
+
+    /// Returns `true` if the latest value of the column satisfies the predicate
+    pub fn matches(&self, latest_value: Option<&CellValue>) -> bool {
+        match self {
+            Predicate::Exists(_) => latest_value.is_some(),
+            Predicate::Compare(comparison) => {
+                latest_value.is_some_and(|value| comparison.op.matches(value, &comparison.value))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Input {
+    pub row_key: String,
+    pub predicate: Predicate,
+
+    /// Mutations applied if the predicate matches
+    #[serde(default)]
+    pub true_mutations: Vec<ColumnWriteItem>,
+
+    /// Deletes applied if the predicate matches, before the mutations are written
+    #[serde(default)]
+    pub true_deletes: Vec<DeleteWriteItem>,
+
+    /// Mutations applied if the predicate does not match
+    #[serde(default)]
+    pub false_mutations: Vec<ColumnWriteItem>,
+
+    /// Deletes applied if the predicate does not match, before the mutations are written
+    #[serde(default)]
+    pub false_deletes: Vec<DeleteWriteItem>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Output {
+    pub predicate_matched: bool,
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::sync::Arc;
+
+/// Opaque token to continue a scan where a previous page ended
+///
+/// It contains the key of the last cell that was visited, so the next page
+/// continues directly after it, even inside a row that was cut short.
+///
+/// Pages are read at the latest state of the table, so writes that happen between
+/// pages may or may not be visible to the next page.
+///
+/// Is serialized as a hex string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContinuationToken {
+    pub(crate) key: Arc<[u8]>,
+}
+
+impl std::fmt::Display for ContinuationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for byte in self.key.iter() {
+            write!(f, "{byte:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TryFrom<&str> for ContinuationToken {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, ()> {
+        if value.is_empty() || value.len() % 2 != 0 || !value.is_ascii() {
+            return Err(());
+        }
+
+        let key = (0..value.len())
+            .step_by(2)
+            .map(|idx| u8::from_str_radix(&value[idx..idx + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ())?;
+
+        Ok(Self { key: key.into() })
+    }
+}
+
+impl Serialize for ContinuationToken {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContinuationToken {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ContinuationTokenVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ContinuationTokenVisitor {
+            type Value = ContinuationToken;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a string representing a continuation token")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                ContinuationToken::try_from(value).map_err(|_| {
+                    serde::de::Error::invalid_value(serde::de::Unexpected::Str(value), &self)
+                })
+            }
+        }
+
+        deserializer.deserialize_str(ContinuationTokenVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn continuation_token_roundtrip() {
+        let token = ContinuationToken {
+            key: (*b"user\x00\x01value\x00\x01").into(),
+        };
+
+        let s = serde_json::to_string(&token).unwrap();
+        let p: ContinuationToken = serde_json::from_str(&s).unwrap();
+        assert_eq!(token, p);
+
+        assert!(ContinuationToken::try_from("").is_err());
+        assert!(ContinuationToken::try_from("abc").is_err());
+        assert!(ContinuationToken::try_from("75zz").is_err());
+    }
+}
@@ -1,22 +1,36 @@
-use super::scan::ScanMode;
+use super::{
+    filter::RowFilter,
+    scan::{ScanMode, TimeRange},
+};
 use crate::ColumnFilter;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RowOptions {
     #[serde(flatten)]
     pub scan: ScanMode,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ColumnOptions {
     #[serde(flatten)]
     pub filter: Option<ColumnFilter>,
 }
-#[derive(Clone, Debug, Deserialize, Serialize)]
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CellOptions {
+    pub time: Option<TimeRange>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Input {
     pub row: RowOptions,
     pub column: Option<ColumnOptions>,
+    pub cell: Option<CellOptions>,
+
+    /// Composable filter that every counted cell has to match
+    #[serde(default)]
+    pub filter: Option<RowFilter>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -0,0 +1,56 @@
+use super::{filter::RowFilter, scan::Range};
+use crate::ColumnFilter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum DeleteMode {
+    #[serde(rename = "keys")]
+    Keys(Vec<String>),
+
+    #[serde(rename = "prefix")]
+    Prefix(String),
+
+    #[serde(rename = "range")]
+    Range(Range),
+}
+
+impl Default for DeleteMode {
+    /// Deletes no rows
+    fn default() -> Self {
+        Self::Keys(vec![])
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RowOptions {
+    #[serde(flatten)]
+    pub mode: DeleteMode,
+
+    /// Maximum number of cells deleted in one batch
+    ///
+    /// If set, large deletes are split into multiple batches,
+    /// each returning a cursor to continue with.
+    pub cell_limit: Option<u32>,
+
+    /// Row key cursor, deletion continues at the given row
+    pub cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct Input {
+    pub row: RowOptions,
+    pub column_filter: Option<ColumnFilter>,
+
+    /// Composable filter that every deleted cell has to match
+    #[serde(default)]
+    pub filter: Option<RowFilter>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Output {
+    pub deleted_cells_count: u64,
+
+    /// Set if the cell limit was reached, can be passed
+    /// as `cursor` to continue deleting
+    pub cursor: Option<String>,
+}
@@ -0,0 +1,253 @@
+use super::check_and_mutate::CompareOp;
+use crate::{CellValue, ColumnKey, VisitedCell};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Regular expression that has to match an entire key
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    source: String,
+    regex: regex::Regex,
+}
+
+impl Pattern {
+    pub fn new(source: &str) -> crate::Result<Self> {
+        let regex = regex::Regex::new(&format!("^(?:{source})$"))
+            .map_err(|_| crate::Error::InvalidPattern(source.into()))?;
+
+        Ok(Self {
+            source: source.into(),
+            regex,
+        })
+    }
+
+    /// Returns `true` if the pattern matches the entire string
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.regex.is_match(haystack)
+    }
+}
+
+impl TryFrom<&str> for Pattern {
+    type Error = crate::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl Serialize for Pattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        Self::new(&source).map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ValueComparison {
+    pub op: CompareOp,
+
+    #[serde(flatten)]
+    pub value: CellValue,
+}
+
+/// Range of cell values
+///
+/// The start is inclusive, the end is exclusive.
+/// Unset bounds are unbounded.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ValueRange {
+    pub start: Option<CellValue>,
+    pub end: Option<CellValue>,
+}
+
+impl ValueRange {
+    /// Returns `true` if the value is inside the value range
+    ///
+    /// Values of a different type than a bound never match.
+    pub fn contains(&self, value: &CellValue) -> bool {
+        self.start.as_ref().map_or(true, |start| {
+            CompareOp::GreaterOrEqual.matches(value, start)
+        }) && self
+            .end
+            .as_ref()
+            .map_or(true, |end| CompareOp::Less.matches(value, end))
+    }
+}
+
+/// Composable filter that is evaluated against every visited cell
+///
+/// Filters are evaluated after the column filter and time range are applied.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum RowFilter {
+    /// The row key matches the regular expression
+    #[serde(rename = "row_key_regex")]
+    RowKeyRegex(Pattern),
+
+    /// The column qualifier matches the regular expression
+    ///
+    /// Cells without a qualifier are matched against an empty string.
+    #[serde(rename = "qualifier_regex")]
+    QualifierRegex(Pattern),
+
+    /// The cell value compares to the given value
+    ///
+    /// Values of different types never match.
+    #[serde(rename = "value")]
+    Value(ValueComparison),
+
+    /// The cell value is inside the value range
+    #[serde(rename = "value_range")]
+    ValueRange(ValueRange),
+
+    /// The cell is one of the latest N versions of its column
+    ///
+    /// Versions are counted independently of the other filters,
+    /// so chaining it with a value filter returns the latest versions
+    /// only if their values match.
+    #[serde(rename = "latest")]
+    Latest(u32),
+
+    /// All filters match (AND)
+    #[serde(rename = "chain")]
+    Chain(Vec<RowFilter>),
+
+    /// Any filter matches (OR)
+    #[serde(rename = "interleave")]
+    Interleave(Vec<RowFilter>),
+
+    /// The filter does not match
+    #[serde(rename = "not")]
+    Not(Box<RowFilter>),
+}
+
+impl RowFilter {
+    /// Returns `true` if the cell matches the filter
+    ///
+    /// `version` is the index of the cell in the version history of its column,
+    /// starting at 0 for the latest version.
+    pub fn matches(&self, cell: &VisitedCell, version: usize) -> bool {
+        match self {
+            RowFilter::RowKeyRegex(pattern) => pattern.is_match(&cell.row_key),
+            RowFilter::QualifierRegex(pattern) => {
+                pattern.is_match(cell.column_key.qualifier.as_deref().unwrap_or_default())
+            }
+            RowFilter::Value(comparison) => comparison.op.matches(&cell.value, &comparison.value),
+            RowFilter::ValueRange(range) => range.contains(&cell.value),
+            RowFilter::Latest(n) => version < *n as usize,
+            RowFilter::Chain(filters) => filters.iter().all(|f| f.matches(cell, version)),
+            RowFilter::Interleave(filters) => filters.iter().any(|f| f.matches(cell, version)),
+            RowFilter::Not(filter) => !filter.matches(cell, version),
+        }
+    }
+}
+
+/// Evaluates a row filter against cells that are visited in key order,
+/// keeping track of the version index of every column
+pub(crate) struct FilterEvaluator {
+    filter: Option<RowFilter>,
+    current_column: Option<(String, ColumnKey)>,
+    version: usize,
+}
+
+impl FilterEvaluator {
+    pub fn new(filter: Option<RowFilter>) -> Self {
+        Self {
+            filter,
+            current_column: None,
+            version: 0,
+        }
+    }
+
+    /// Returns `true` if the cell matches the filter, or no filter is set
+    pub fn matches(&mut self, cell: &VisitedCell) -> bool {
+        let Some(filter) = &self.filter else {
+            return true;
+        };
+
+        let is_same_column = self
+            .current_column
+            .as_ref()
+            .is_some_and(|(row_key, column_key)| {
+                row_key == &cell.row_key && column_key == &cell.column_key
+            });
+
+        if is_same_column {
+            self.version += 1;
+        } else {
+            self.current_column = Some((cell.row_key.clone(), cell.column_key.clone()));
+            self.version = 0;
+        }
+
+        filter.matches(cell, self.version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    fn visited_cell(row_key: &str, column_key: &str, value: CellValue) -> VisitedCell {
+        VisitedCell {
+            raw_key: [].into(),
+            row_key: row_key.into(),
+            column_key: ColumnKey::try_from(column_key).expect("should be column key"),
+            timestamp: 0,
+            value,
+        }
+    }
+
+    #[test]
+    fn row_filter_pattern_full_match() -> crate::Result<()> {
+        let pattern = Pattern::new("user:[0-9]+")?;
+
+        assert!(pattern.is_match("user:12"));
+        assert!(!pattern.is_match("user:12a"));
+        assert!(!pattern.is_match("xuser:12"));
+
+        assert!(matches!(
+            Pattern::new("user:("),
+            Err(crate::Error::InvalidPattern(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn row_filter_combinators() -> crate::Result<()> {
+        let cell = visited_cell("user:1", "value:name", CellValue::I32(5));
+
+        let filter = RowFilter::Chain(vec![
+            RowFilter::RowKeyRegex(Pattern::new("user:.*")?),
+            RowFilter::Not(Box::new(RowFilter::Interleave(vec![
+                RowFilter::QualifierRegex(Pattern::new("age")?),
+                RowFilter::ValueRange(ValueRange {
+                    start: Some(CellValue::I32(10)),
+                    end: None,
+                }),
+            ]))),
+        ]);
+
+        assert!(filter.matches(&cell, 0));
+        assert!(!RowFilter::Latest(1).matches(&cell, 1));
+        assert!(!RowFilter::Interleave(vec![]).matches(&cell, 0));
+        assert!(RowFilter::Chain(vec![]).matches(&cell, 0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn row_filter_evaluator_versions() {
+        let mut evaluator = FilterEvaluator::new(Some(RowFilter::Latest(1)));
+
+        assert!(evaluator.matches(&visited_cell("a", "value:", CellValue::I32(1))));
+        assert!(!evaluator.matches(&visited_cell("a", "value:", CellValue::I32(2))));
+        assert!(evaluator.matches(&visited_cell("a", "value:a", CellValue::I32(3))));
+        assert!(evaluator.matches(&visited_cell("b", "value:a", CellValue::I32(4))));
+    }
+}
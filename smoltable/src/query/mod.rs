@@ -1,3 +1,9 @@
+pub mod aggregate;
+pub mod check_and_mutate;
+pub mod continuation;
 pub mod count;
+pub mod delete;
+pub mod filter;
+pub mod read_modify_write;
 pub mod row;
 pub mod scan;
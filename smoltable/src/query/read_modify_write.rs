@@ -0,0 +1,60 @@
+use crate::{CellValue, ColumnKey, Row};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Operation {
+    /// Adds to an `i32`, `i64`, `u64` or `f64` cell, integers wrap around on overflow
+    #[serde(rename = "increment")]
+    Increment(CellValue),
+
+    /// Appends to a `string` cell
+    #[serde(rename = "append")]
+    Append(String),
+}
+
+impl Operation {
+    /// Returns the new value, based on the latest value of the column
+    ///
+    /// Returns `None` if the operation is not applicable to the value type.
+    pub fn apply(&self, latest_value: Option<&CellValue>) -> Option<CellValue> {
+        use CellValue::{String, F64, I32, I64, U64};
+
+        match (self, latest_value) {
+            (Operation::Increment(value @ (I32(_) | I64(_) | U64(_) | F64(_))), None) => {
+                Some(value.clone())
+            }
+            (Operation::Increment(I32(by)), Some(I32(value))) => Some(I32(value.wrapping_add(*by))),
+            (Operation::Increment(I64(by)), Some(I64(value))) => Some(I64(value.wrapping_add(*by))),
+            (Operation::Increment(U64(by)), Some(U64(value))) => Some(U64(value.wrapping_add(*by))),
+            (Operation::Increment(F64(by)), Some(F64(value))) => Some(F64(value + by)),
+            (Operation::Append(suffix), None) => Some(String(suffix.clone())),
+            (Operation::Append(suffix), Some(String(value))) => {
+                Some(String(format!("{value}{suffix}")))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Rule {
+    pub column_key: ColumnKey,
+
+    #[serde(flatten)]
+    pub operation: Operation,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Input {
+    pub row_key: String,
+
+    /// Rules are applied in order; multiple rules on the same column compose,
+    /// and each column is written only once
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Output {
+    /// Contains the new value of every modified column
+    pub row: Row,
+}
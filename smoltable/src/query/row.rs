@@ -1,33 +1,41 @@
-use crate::{ColumnFilter, Row};
+use super::{filter::RowFilter, scan::TimeRange};
+use crate::{ColumnFilter, ColumnKey, Row};
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RowOptions {
     pub key: String,
     pub cell_limit: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ColumnOptions {
     pub cell_limit: Option<u32>,
 
-    // TODO: column limit
+    /// Maximum number of columns per row
+    pub limit: Option<u32>,
+
+    /// Column cursor, only columns after the given column are visited
+    pub start_after: Option<ColumnKey>,
 
-    // pub start, end: Option<Range>, // TODO: .......
     #[serde(flatten)]
     pub filter: Option<ColumnFilter>,
 }
 
-/* #[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CellOptions {
-    // pub time: Option<Range>, // TODO:
-} */
+    pub time: Option<TimeRange>,
+}
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Input {
     pub row: RowOptions,
     pub column: Option<ColumnOptions>,
-    // pub cell: Option<CellOptions>, // TODO:
+    pub cell: Option<CellOptions>,
+
+    /// Composable filter that every returned cell has to match
+    #[serde(default)]
+    pub filter: Option<RowFilter>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -36,4 +44,8 @@ pub struct Output {
     pub row: Option<Row>,
     pub cells_scanned_count: u64,
     pub bytes_scanned_count: u64,
+
+    /// Set if the column limit was reached, can be passed
+    /// as `start_after` to continue with the next columns
+    pub column_cursor: Option<ColumnKey>,
 }
@@ -1,4 +1,5 @@
-use crate::{ColumnFilter, Row};
+use super::{continuation::ContinuationToken, filter::RowFilter};
+use crate::{ColumnFilter, ColumnKey, Row};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -8,6 +9,35 @@ pub struct Range {
     pub inclusive: bool,
 }
 
+/// Timestamp range of cell versions
+///
+/// The start is inclusive, the end is exclusive.
+/// Unset bounds are unbounded.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct TimeRange {
+    pub start: Option<u128>,
+    pub end: Option<u128>,
+}
+
+impl TimeRange {
+    /// Returns `true` if the timestamp is inside the time range
+    pub fn contains(&self, timestamp: u128) -> bool {
+        self.start.map_or(true, |start| timestamp >= start)
+            && self.end.map_or(true, |end| timestamp < end)
+    }
+}
+
+impl Range {
+    /// Returns `true` if the row key sorts after the end of the range
+    pub fn is_past_end(&self, row_key: &str) -> bool {
+        if self.inclusive {
+            row_key > self.end.as_str()
+        } else {
+            row_key >= self.end.as_str()
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum ScanMode {
     #[serde(rename = "prefix")]
@@ -15,12 +45,47 @@ pub enum ScanMode {
 
     #[serde(rename = "range")]
     Range(Range),
-    // TODO:
-    /*  #[serde(rename = "ranges")]
-    Ranges(Vec<Range>), */
+
+    /// Multiple ranges, visited in ascending order of their start key
+    #[serde(rename = "ranges")]
+    Ranges(Vec<Range>),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+impl Default for ScanMode {
+    /// Scans all rows
+    fn default() -> Self {
+        Self::Prefix(String::new())
+    }
+}
+
+impl ScanMode {
+    /// Splits the scan mode into single-range scan modes,
+    /// sorted by their start key
+    pub(crate) fn split(&self) -> Vec<ScanMode> {
+        match self {
+            ScanMode::Ranges(ranges) => {
+                let mut ranges = ranges.clone();
+                ranges.sort_by(|a, b| a.start.cmp(&b.start));
+                ranges.into_iter().map(ScanMode::Range).collect()
+            }
+            _ => vec![self.clone()],
+        }
+    }
+
+    /// Returns `true` if the row key is outside the scan bounds
+    ///
+    /// Because cells are visited in key order, the scan can be terminated
+    /// once this returns `true`.
+    pub(crate) fn is_out_of_bounds(&self, row_key: &str) -> bool {
+        match self {
+            ScanMode::Prefix(prefix) => !row_key.starts_with(prefix),
+            ScanMode::Range(range) => range.is_past_end(row_key),
+            ScanMode::Ranges(ranges) => ranges.iter().all(|range| range.is_past_end(row_key)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct RowOptions {
     #[serde(flatten)]
     pub scan: ScanMode,
@@ -30,30 +95,40 @@ pub struct RowOptions {
     pub cell_limit: Option<u32>,
 
     pub sample: Option<f32>,
+
+    /// Continues a previous scan after its last visited cell
+    pub continuation: Option<ContinuationToken>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct ColumnOptions {
     pub cell_limit: Option<u32>,
 
-    // TODO: column limit
+    /// Maximum number of columns per row
+    pub limit: Option<u32>,
+
+    /// Column cursor, only columns after the given column are visited
+    pub start_after: Option<ColumnKey>,
 
-    // pub start, end: Option<Range>, // TODO: .......
     #[serde(flatten)]
     pub filter: Option<ColumnFilter>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CellOptions {
     pub limit: Option<u32>,
-    // pub time: Option<Range>, // TODO:
+    pub time: Option<TimeRange>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct Input {
     pub column: Option<ColumnOptions>,
     pub row: RowOptions,
     pub cell: Option<CellOptions>,
+
+    /// Composable filter that every returned cell has to match
+    #[serde(default)]
+    pub filter: Option<RowFilter>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -63,4 +138,29 @@ pub struct Output {
     pub cells_scanned_count: u64,
     pub rows_scanned_count: u64,
     pub bytes_scanned_count: u64,
+
+    /// Set if the scan was cut short by a limit, can be passed
+    /// as `continuation` to retrieve the next page
+    pub continuation: Option<ContinuationToken>,
+
+    /// Set if the column limit of the last returned row was reached,
+    /// the next page continues with the columns after it
+    pub column_cursor: Option<ColumnKey>,
+}
+
+/// Statistics of a scan whose rows are passed to a callback
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Stats {
+    pub affected_locality_groups: usize,
+    pub cells_scanned_count: u64,
+    pub rows_scanned_count: u64,
+    pub bytes_scanned_count: u64,
+
+    /// Set if the scan was cut short by a limit, can be passed
+    /// as `continuation` to retrieve the next page
+    pub continuation: Option<ContinuationToken>,
+
+    /// Set if the column limit of the last returned row was reached,
+    /// the next page continues with the columns after it
+    pub column_cursor: Option<ColumnKey>,
 }
@@ -0,0 +1,520 @@
+use super::{merge_reader::MergeReader, row_reader::get_affected_locality_groups, Smoltable};
+use crate::{
+    query::{
+        continuation::ContinuationToken,
+        filter::{FilterEvaluator, RowFilter},
+        scan::{Input as QueryPrefixInput, ScanMode, Stats as QueryPrefixStats, TimeRange},
+    },
+    Cell, ColumnFilter, ColumnKey, Row, VisitedCell,
+};
+use fjall::PartitionHandle;
+use std::{collections::HashMap, ops::Bound, sync::Arc, vec::IntoIter};
+
+/// Lazily visits all cells of a scan that match its column filter, time range and row filter
+///
+/// Cells are visited in key order, so all versions of a column are adjacent,
+/// ordered from newest to oldest.
+pub struct CellIterator {
+    instant: fjall::Instant,
+    locality_groups: Vec<PartitionHandle>,
+
+    /// Single-range scans that are not visited yet
+    scans: IntoIter<ScanMode>,
+
+    /// The single-range scan that is currently visited
+    current: Option<(ScanMode, MergeReader)>,
+
+    // NOTE: Remember the last visited key, so overlapping ranges
+    // do not visit the same cells twice
+    last_key: Option<Arc<[u8]>>,
+
+    column_filter: Option<ColumnFilter>,
+
+    /// Column cursor, only applies to the first visited row
+    column_cursor: Option<ColumnKey>,
+    column_cursor_row: Option<String>,
+
+    time_range: Option<TimeRange>,
+    row_filter: FilterEvaluator,
+
+    cells_scanned_count: u64,
+    bytes_scanned_count: u64,
+}
+
+impl CellIterator {
+    /// Creates a cell iterator over a snapshot of the table
+    ///
+    /// If a `resume_after` key is given, cells up to and including it are skipped.
+    pub(crate) fn new(
+        table: &Smoltable,
+        instant: fjall::Instant,
+        scan: &ScanMode,
+        resume_after: Option<Arc<[u8]>>,
+        column_filter: Option<ColumnFilter>,
+        time_range: Option<TimeRange>,
+        filter: Option<RowFilter>,
+    ) -> crate::Result<Self> {
+        let locality_groups = get_affected_locality_groups(table, &column_filter)?;
+
+        Ok(Self {
+            instant,
+            locality_groups,
+            scans: scan.split().into_iter(),
+            current: None,
+            last_key: resume_after,
+            column_filter,
+            column_cursor: None,
+            column_cursor_row: None,
+            time_range,
+            row_filter: FilterEvaluator::new(filter),
+            cells_scanned_count: 0,
+            bytes_scanned_count: 0,
+        })
+    }
+
+    /// Skips all columns of the first visited row up to and including the given column
+    ///
+    /// The first visited row is the row a continuation resumes in.
+    pub(crate) fn start_after_column(mut self, column_key: Option<ColumnKey>) -> Self {
+        self.column_cursor = column_key;
+        self
+    }
+
+    /// Continues the current range at the given key
+    pub(crate) fn seek(&mut self, start: Bound<Arc<[u8]>>) {
+        if let Some((_, reader)) = &mut self.current {
+            reader.seek(start);
+        }
+    }
+
+    /// Returns the amount of locality groups that are scanned
+    pub fn affected_locality_groups(&self) -> usize {
+        self.locality_groups.len()
+    }
+
+    /// Returns the amount of cells that have been read so far, including filtered cells
+    pub fn cells_scanned_count(&self) -> u64 {
+        self.cells_scanned_count
+            + self
+                .current
+                .as_ref()
+                .map_or(0, |(_, reader)| reader.cells_scanned_count())
+    }
+
+    /// Returns the amount of bytes that have been read so far, including filtered cells
+    pub fn bytes_scanned_count(&self) -> u64 {
+        self.bytes_scanned_count
+            + self
+                .current
+                .as_ref()
+                .map_or(0, |(_, reader)| reader.bytes_scanned_count())
+    }
+
+    /// Finishes the current range, returning `false` if there are no ranges left
+    fn next_range(&mut self) -> crate::Result<bool> {
+        if let Some((_, reader)) = self.current.take() {
+            self.cells_scanned_count += reader.cells_scanned_count();
+            self.bytes_scanned_count += reader.bytes_scanned_count();
+        }
+
+        let Some(scan) = self.scans.next() else {
+            return Ok(false);
+        };
+
+        let readers = Smoltable::get_scan_readers(
+            self.instant,
+            &self.locality_groups,
+            &scan,
+            self.last_key.as_ref(),
+        )?;
+
+        self.current = Some((scan, MergeReader::new(readers)));
+
+        Ok(true)
+    }
+}
+
+impl Iterator for CellIterator {
+    type Item = crate::Result<VisitedCell>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((scan, reader)) = &mut self.current else {
+                match self.next_range() {
+                    Ok(true) => continue,
+                    Ok(false) => return None,
+                    Err(e) => return Some(Err(e)),
+                }
+            };
+
+            let cell = match (&mut *reader).next() {
+                Some(Ok(cell)) if !scan.is_out_of_bounds(&cell.row_key) => cell,
+                Some(Err(e)) => return Some(Err(e)),
+                _ => {
+                    // NOTE: The current range is exhausted, continue with the next one
+                    match self.next_range() {
+                        Ok(true) => continue,
+                        Ok(false) => return None,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+            };
+
+            self.last_key = Some(cell.raw_key.clone());
+
+            if self.column_cursor.is_some() {
+                let cursor_row = self
+                    .column_cursor_row
+                    .get_or_insert_with(|| cell.row_key.clone());
+
+                if *cursor_row != cell.row_key {
+                    self.column_cursor = None;
+                }
+            }
+
+            if let Some(filter) = &self.column_filter {
+                if !cell.satisfies_column_filter(filter) {
+                    continue;
+                }
+            }
+
+            if let Some(column_cursor) = &self.column_cursor {
+                let cursor_key = VisitedCell::format_key(&cell.row_key, column_cursor, 0);
+
+                if *cell.raw_key <= *cursor_key {
+                    // NOTE: Seek past all versions of the cursor column
+                    reader.seek(Bound::Excluded(cursor_key.into()));
+                    continue;
+                }
+            }
+
+            if let Some(time_range) = &self.time_range {
+                if let Some(start) = cell.seek_key_for_time_range(time_range) {
+                    reader.seek(start);
+                    continue;
+                }
+            }
+
+            if !self.row_filter.matches(&cell) {
+                continue;
+            }
+
+            return Some(Ok(cell));
+        }
+    }
+}
+
+/// Lazily groups the cells of a scan into rows
+///
+/// Only the row that is currently visited is buffered, so arbitrarily large
+/// scans can be processed with bounded memory, and stopped at any time.
+pub struct RowIterator {
+    cells: CellIterator,
+    sample_rate: Option<f32>,
+
+    row_offset: u64,
+    row_limit: usize,
+    row_cell_limit: usize,
+    row_column_limit: usize,
+    column_cell_limit: usize,
+    global_cell_limit: usize,
+
+    row_sample_counter: f32,
+    is_row_skipped: bool,
+
+    current_row: Option<Row>,
+    returned_row_count: usize,
+
+    /// Column and key of the last cell that was added to the current row
+    current_column: Option<ColumnKey>,
+    last_cell_key: Option<Arc<[u8]>>,
+
+    rows_scanned_count: u64,
+    cell_count: usize, // Cell count over all returned rows
+
+    /// Set once a limit is reached, the next page continues after this key
+    resume_key: Option<Arc<[u8]>>,
+
+    /// Set once the column limit of a row is reached, the last returned column of the row
+    column_cursor: Option<ColumnKey>,
+    is_column_limit_reached: bool,
+
+    is_done: bool,
+}
+
+impl RowIterator {
+    pub(crate) fn new(table: &Smoltable, input: QueryPrefixInput) -> crate::Result<Self> {
+        let column_filter = input.column.as_ref().and_then(|x| x.filter.clone());
+        let time_range = input.cell.as_ref().and_then(|x| x.time.clone());
+
+        // NOTE: If a continuation token is given, we seek directly past its last visited cell
+        let resume_after = input.row.continuation.map(|token| token.key);
+
+        let cells = CellIterator::new(
+            table,
+            table.keyspace.instant(),
+            &input.row.scan,
+            resume_after,
+            column_filter,
+            time_range,
+            input.filter,
+        )?
+        .start_after_column(input.column.as_ref().and_then(|x| x.start_after.clone()));
+
+        let column = input.column.as_ref();
+
+        Ok(Self {
+            cells,
+            sample_rate: input.row.sample,
+
+            row_offset: input.row.offset.unwrap_or_default() as u64,
+            row_limit: input.row.limit.unwrap_or(u32::from(u16::MAX)) as usize,
+            row_cell_limit: input.row.cell_limit.unwrap_or(u32::from(u16::MAX)) as usize,
+            row_column_limit: column.and_then(|x| x.limit).unwrap_or(u32::MAX) as usize,
+            column_cell_limit: column
+                .and_then(|x| x.cell_limit)
+                .unwrap_or(u32::from(u16::MAX)) as usize,
+            global_cell_limit: input
+                .cell
+                .as_ref()
+                .and_then(|x| x.limit)
+                .unwrap_or(u32::from(u16::MAX)) as usize,
+
+            row_sample_counter: 1.0,
+            is_row_skipped: false,
+
+            current_row: None,
+            returned_row_count: 0,
+
+            current_column: None,
+            last_cell_key: None,
+
+            rows_scanned_count: 0,
+            cell_count: 0,
+
+            resume_key: None,
+
+            column_cursor: None,
+            is_column_limit_reached: false,
+
+            is_done: false,
+        })
+    }
+
+    /// Returns the statistics of the rows that have been visited so far
+    ///
+    /// The continuation is only set once the scan has been cut short by a limit.
+    pub fn stats(&self) -> QueryPrefixStats {
+        let continuation = self.resume_key.clone().map(|key| ContinuationToken { key });
+
+        QueryPrefixStats {
+            affected_locality_groups: self.cells.affected_locality_groups(),
+            cells_scanned_count: self.cells.cells_scanned_count(),
+            rows_scanned_count: self.rows_scanned_count,
+            bytes_scanned_count: self.cells.bytes_scanned_count(),
+            continuation,
+            column_cursor: self.column_cursor.clone(),
+        }
+    }
+
+    /// Takes the buffered row, if it has any columns
+    fn take_row(&mut self) -> Option<Row> {
+        self.current_row.take().filter(|row| row.column_count() > 0)
+    }
+
+    /// Returns the key after which the next page continues, if the row is cut short
+    ///
+    /// Cells that would not have been returned because of the per-row limits are skipped.
+    fn resume_key_in_row(&self, row: &Row) -> Arc<[u8]> {
+        if row.cell_count() < self.row_cell_limit {
+            if let (Some(column_key), Some(last_cell_key)) =
+                (&self.current_column, &self.last_cell_key)
+            {
+                let version_count = row
+                    .columns
+                    .get(&column_key.family)
+                    .and_then(|family| {
+                        family.get(column_key.qualifier.as_deref().unwrap_or_default())
+                    })
+                    .map_or(0, Vec::len);
+
+                if version_count < self.column_cell_limit {
+                    return last_cell_key.clone();
+                }
+
+                // NOTE: Skip the remaining versions of the column
+                return VisitedCell::format_key(&row.row_key, column_key, 0).into();
+            }
+        }
+
+        VisitedCell::format_row_end_key(&row.row_key).into()
+    }
+
+    /// Starts buffering a new row
+    fn start_row(&mut self, row_key: String) {
+        self.current_row = Some(Row {
+            row_key,
+            columns: HashMap::default(),
+        });
+
+        self.current_column = None;
+        self.last_cell_key = None;
+
+        self.is_row_skipped = self.rows_scanned_count <= self.row_offset;
+
+        if let Some(sample_rate) = self.sample_rate {
+            if sample_rate < 1.0 && !self.is_row_skipped {
+                self.row_sample_counter += sample_rate;
+
+                if self.row_sample_counter < 1.0 {
+                    self.is_row_skipped = true;
+                } else {
+                    self.row_sample_counter -= 1.0;
+                }
+            }
+        }
+    }
+
+    /// Adds a cell to the buffered row
+    fn push_cell(&mut self, cell: VisitedCell) {
+        if self.is_row_skipped {
+            return;
+        }
+
+        let row = self.current_row.as_mut().expect("current row should exist");
+
+        if row.cell_count() >= self.row_cell_limit {
+            return;
+        }
+
+        let is_new_column = !row
+            .columns
+            .get(&cell.column_key.family)
+            .is_some_and(|family| {
+                family.contains_key(cell.column_key.qualifier.as_deref().unwrap_or_default())
+            });
+
+        if is_new_column && row.column_count() >= self.row_column_limit {
+            if row.column_count() > 0 {
+                // NOTE: The page ends here, so the next page continues with the next column
+                self.is_column_limit_reached = true;
+            } else {
+                // NOTE: Columns are visited in order, so we can skip the rest of the row
+                let row_end = VisitedCell::format_row_end_key(&row.row_key);
+                self.cells.seek(Bound::Included(row_end.into()));
+            }
+
+            return;
+        }
+
+        if is_new_column {
+            self.current_column = Some(cell.column_key.clone());
+        }
+
+        let version_history = row
+            .columns
+            .entry(cell.column_key.family)
+            .or_default()
+            .entry(cell.column_key.qualifier.unwrap_or_default())
+            .or_default();
+
+        if version_history.len() >= self.column_cell_limit {
+            return;
+        }
+
+        version_history.push(Cell {
+            timestamp: cell.timestamp,
+            value: cell.value,
+        });
+
+        self.last_cell_key = Some(cell.raw_key);
+        self.cell_count += 1;
+    }
+}
+
+impl Iterator for RowIterator {
+    type Item = crate::Result<Row>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.is_done {
+                return None;
+            }
+
+            // We are gonna visit another cell, if the global cell limit is reached
+            // we can short circuit out of the loop
+            if self.cell_count >= self.global_cell_limit {
+                self.is_done = true;
+
+                // NOTE: The row may be cut short, so the next page continues inside of it
+                let row = self.take_row()?;
+                self.resume_key = Some(self.resume_key_in_row(&row));
+
+                return Some(Ok(row));
+            }
+
+            let cell = match self.cells.next() {
+                Some(Ok(cell)) => cell,
+                Some(Err(e)) => {
+                    self.is_done = true;
+                    return Some(Err(e));
+                }
+                None => {
+                    self.is_done = true;
+                    return self.take_row().map(Ok);
+                }
+            };
+
+            if self.current_row.as_ref().map(|row| &row.row_key) == Some(&cell.row_key) {
+                self.push_cell(cell);
+
+                if self.is_column_limit_reached {
+                    self.is_done = true;
+
+                    let row = self.take_row()?;
+                    let column_key = self.current_column.clone()?;
+
+                    // NOTE: Skip the remaining versions of the last returned column
+                    self.resume_key =
+                        Some(VisitedCell::format_key(&row.row_key, &column_key, 0).into());
+                    self.column_cursor = Some(column_key);
+
+                    return Some(Ok(row));
+                }
+
+                continue;
+            }
+
+            // We are visiting a new row, so the previous row is complete
+            //
+            // IMPORTANT: Even if a row has no matching columns, we need to temporarily buffer it,
+            // so we can track in which row we are currently in (to increment `rows_scanned_count`)
+            // After that it gets dropped, if the column count stays 0
+            let completed_row = self.take_row();
+
+            if completed_row.is_some() {
+                self.returned_row_count += 1;
+            }
+
+            self.rows_scanned_count += 1;
+
+            // If the row limit is reached
+            // we can short circuit out of the loop
+            if self.returned_row_count == self.row_limit {
+                self.is_done = true;
+
+                let row = completed_row?;
+                self.resume_key = Some(VisitedCell::format_row_end_key(&row.row_key).into());
+
+                return Some(Ok(row));
+            }
+
+            self.start_row(cell.row_key.clone());
+            self.push_cell(cell);
+
+            if let Some(row) = completed_row {
+                return Some(Ok(row));
+            }
+        }
+    }
+}
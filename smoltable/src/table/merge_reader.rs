@@ -1,5 +1,6 @@
 use super::reader::Reader as TableReader;
 use crate::VisitedCell;
+use std::{ops::Bound, sync::Arc};
 
 pub struct MergeReader {
     readers: Vec<TableReader>,
@@ -10,6 +11,15 @@ impl MergeReader {
         Self { readers }
     }
 
+    /// Skips all cells before the given key bound in all readers
+    ///
+    /// The bound needs to be after the last visited cell.
+    pub fn seek(&mut self, start: Bound<Arc<[u8]>>) {
+        for reader in &mut self.readers {
+            reader.seek(start.clone());
+        }
+    }
+
     pub fn cells_scanned_count(&self) -> u64 {
         self.readers.iter().map(|x| x.cells_scanned_count).sum()
     }
@@ -20,7 +30,7 @@ impl MergeReader {
 }
 
 impl Iterator for &mut MergeReader {
-    type Item = fjall::Result<VisitedCell>;
+    type Item = crate::Result<VisitedCell>;
 
     fn next(&mut self) -> Option<Self::Item> {
         // Peek all readers
@@ -34,25 +44,44 @@ impl Iterator for &mut MergeReader {
         let cells = match cells
             .into_iter()
             .map(Option::transpose)
-            .collect::<fjall::Result<Vec<Option<VisitedCell>>>>()
+            .collect::<crate::Result<Vec<Option<VisitedCell>>>>()
         {
             Ok(cells) => cells,
             Err(e) => return Some(Err(e)),
         };
 
         // Get index of reader that has lowest row
-        let lowest_idx = cells
-            .into_iter()
+        let lowest = cells
+            .iter()
             .enumerate()
-            .filter(|(_, cell)| Option::is_some(cell))
-            .map(|(idx, cell)| (idx, cell.unwrap()))
+            .filter_map(|(idx, cell)| cell.as_ref().map(|cell| (idx, cell)))
             .min_by(|(_, a), (_, b)| a.raw_key.cmp(&b.raw_key));
 
-        let Some((lowest_idx, _)) = lowest_idx else {
+        let Some((lowest_idx, lowest_cell)) = lowest else {
             // No more items
             return None;
         };
 
+        // NOTE: While column families are regrouped, a cell may temporarily
+        // exist in two locality groups, so skip its duplicates
+        let duplicate_indexes = cells
+            .iter()
+            .enumerate()
+            .filter(|(idx, cell)| {
+                *idx != lowest_idx
+                    && cell
+                        .as_ref()
+                        .is_some_and(|cell| cell.raw_key == lowest_cell.raw_key)
+            })
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        for idx in duplicate_indexes {
+            if let Some(Err(e)) = self.readers.get_mut(idx).unwrap().next() {
+                return Some(Err(e));
+            }
+        }
+
         // Consume from iterator with lowest item
         let cell = match self.readers.get_mut(lowest_idx).unwrap().next().transpose() {
             Ok(cell) => cell,
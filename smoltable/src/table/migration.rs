@@ -0,0 +1,141 @@
+use super::Smoltable;
+use crate::{key::FORMAT_VERSION, ColumnKey, VisitedCell};
+use fjall::PartitionHandle;
+
+/// Manifest key of the on-disk key format version
+pub const FORMAT_VERSION_KEY: &str = "format#version";
+
+/// Amount of migrated cells per write batch
+const MIGRATION_BATCH_SIZE: usize = 10_000;
+
+/// Parses a key in the legacy (version 1) format `row_key:cf:cq:!ts`
+///
+/// Legacy keys cannot distinguish a ':' inside the qualifier from the delimiter,
+/// so the qualifier is assumed to be the last segment.
+fn parse_legacy_key(key: &[u8]) -> Option<(String, ColumnKey, u128)> {
+    let ts_start = key.len().checked_sub(std::mem::size_of::<u128>())?;
+
+    // NOTE: -1 because of : delimiter
+    let key_without_ts = key.get(0..ts_start.checked_sub(1)?)?;
+
+    let ts = !u128::from_be_bytes(key.get(ts_start..)?.try_into().ok()?);
+
+    let mut parsed_key = key_without_ts.rsplitn(3, |&e| e == b':');
+
+    let cq = std::str::from_utf8(parsed_key.next()?).ok()?;
+    let cf = std::str::from_utf8(parsed_key.next()?).ok()?;
+    let row_key = std::str::from_utf8(parsed_key.next()?).ok()?;
+
+    Some((
+        row_key.to_owned(),
+        ColumnKey {
+            family: cf.to_owned(),
+            qualifier: Some(cq.to_owned()),
+        },
+        ts,
+    ))
+}
+
+impl Smoltable {
+    /// Returns the on-disk key format version of the table
+    ///
+    /// Tables without a format marker were created before it was introduced (version 1).
+    pub fn key_format_version(&self) -> crate::Result<u32> {
+        let Some(bytes) = self.manifest.get(FORMAT_VERSION_KEY)? else {
+            return Ok(1);
+        };
+
+        let bytes = bytes
+            .get(0..4)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| crate::Error::Decode(bytes.clone()))?;
+
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    /// Rewrites all cells to the current key format, if needed
+    pub(crate) fn migrate_key_format(&self) -> crate::Result<()> {
+        let version = self.key_format_version()?;
+
+        if version == FORMAT_VERSION {
+            return Ok(());
+        }
+
+        if version > FORMAT_VERSION {
+            return Err(crate::Error::UnsupportedFormatVersion(version));
+        }
+
+        log::info!(
+            "Migrating table {:?} from key format v{version} to v{FORMAT_VERSION}",
+            self.name
+        );
+
+        let mut partitions = vec![self.tree.clone()];
+        partitions.extend(
+            self.locality_groups
+                .read()
+                .expect("lock is poisoned")
+                .iter()
+                .map(|lg| lg.tree.clone()),
+        );
+
+        let mut migrated_cells_count = 0;
+
+        for partition in &partitions {
+            migrated_cells_count += self.migrate_partition(partition)?;
+        }
+
+        self.manifest
+            .insert(FORMAT_VERSION_KEY, FORMAT_VERSION.to_be_bytes())?;
+        self.keyspace.persist(fjall::PersistMode::SyncAll)?;
+
+        log::info!(
+            "Migrated {migrated_cells_count} cells of table {:?}",
+            self.name
+        );
+
+        Ok(())
+    }
+
+    fn migrate_partition(&self, partition: &PartitionHandle) -> crate::Result<u64> {
+        let snapshot = partition.snapshot();
+
+        let mut batch = self.batch();
+        let mut batch_len = 0;
+        let mut migrated_cells_count = 0;
+
+        for item in snapshot.iter() {
+            let (key, value) = item?;
+
+            // NOTE: If a previous migration was interrupted, some cells are already migrated
+            if VisitedCell::parse(key.clone(), &value).is_ok() {
+                continue;
+            }
+
+            let Some((row_key, column_key, timestamp)) = parse_legacy_key(&key) else {
+                log::warn!("Skipping migration of corrupt cell {key:?}");
+                continue;
+            };
+
+            batch.remove(partition, &key);
+            batch.insert(
+                partition,
+                VisitedCell::format_key(&row_key, &column_key, timestamp),
+                value,
+            );
+
+            batch_len += 1;
+            migrated_cells_count += 1;
+
+            if batch_len >= MIGRATION_BATCH_SIZE {
+                batch.commit()?;
+                batch = self.batch();
+                batch_len = 0;
+            }
+        }
+
+        batch.commit()?;
+
+        Ok(migrated_cells_count)
+    }
+}
@@ -1,28 +1,46 @@
+mod iterator;
 pub mod merge_reader;
+mod migration;
 pub mod reader;
+mod regroup;
+pub mod row_lock;
 pub mod row_reader;
 pub mod writer;
 
-use self::row_reader::SingleRowReader;
+pub use self::iterator::{CellIterator, RowIterator};
+pub use self::regroup::{LocalityGroupTarget, RegroupColumnFamiliesInput};
+use self::{regroup::RegroupMirror, row_reader::SingleRowReader, writer::CellMutation};
 use crate::{
     query::{
+        aggregate::{Group as AggregateGroup, Input as AggregateInput, Output as AggregateOutput},
+        check_and_mutate::{Input as CheckAndMutateInput, Output as CheckAndMutateOutput},
         count::{Input as CountInput, Output as CountOutput},
+        delete::{
+            DeleteMode, Input as DeleteInput, Output as DeleteOutput,
+            RowOptions as DeleteRowOptions,
+        },
+        filter::FilterEvaluator,
+        read_modify_write::{Input as ReadModifyWriteInput, Output as ReadModifyWriteOutput},
         row::{
             ColumnOptions as QueryRowColumnOptions, Input as QueryRowInput,
             Output as QueryRowOutput, RowOptions as QueryRowInputRowOptions,
         },
-        scan::{Input as QueryPrefixInput, Output as QueryPrefixOutput, ScanMode},
+        scan::{
+            Input as QueryPrefixInput, Output as QueryPrefixOutput, ScanMode,
+            Stats as QueryPrefixStats,
+        },
     },
     table::{
-        merge_reader::MergeReader, row_reader::get_affected_locality_groups, writer::timestamp_nano,
+        merge_reader::MergeReader, row_lock::RowLocks, row_reader::get_affected_locality_groups,
+        writer::timestamp_nano,
     },
-    Cell, ColumnFilter, ColumnKey, Row,
+    Cell, CellValueType, ColumnFilter, ColumnKey, ColumnWriteItem, Row, RowFilter, RowWriteItem,
 };
 use fjall::{Batch, Keyspace, PartitionHandle};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, HashMap, HashSet},
-    sync::{Arc, RwLock},
+    sync::{Arc, Mutex, RwLock},
 };
 
 // NOTE: Bigger block size is advantageous for Smoltable, because:
@@ -31,13 +49,72 @@ use std::{
 /// The block size used by Smoltable (64 KiB)
 pub const BLOCK_SIZE: u32 = /* 64 KiB */ 64 * 1024;
 
+/// The amount of row lock stripes per table
+const ROW_LOCK_STRIPE_COUNT: usize = 64;
+
+/// The maximum amount of cells deleted in a single batch
+const DELETE_BATCH_SIZE: u64 = 10_000;
+
 #[derive(Clone)]
 pub struct LocalityGroup {
     pub id: Arc<str>,
     pub column_families: Vec<Arc<str>>,
+    pub options: LocalityGroupOptions,
     pub tree: PartitionHandle,
 }
 
+/// Compaction strategy of a locality group
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum CompactionOptions {
+    /// Levelled compaction, good read & space amplification
+    #[serde(rename = "levelled")]
+    Levelled { target_size: u32, l0_threshold: u8 },
+
+    /// Size-tiered compaction, good write amplification
+    #[serde(rename = "tiered")]
+    Tiered { base_size: u32 },
+
+    /// FIFO compaction, drops the oldest data when the size limit is reached
+    #[serde(rename = "fifo")]
+    Fifo { limit: u64, ttl_secs: Option<u64> },
+}
+
+impl CompactionOptions {
+    fn to_strategy(&self) -> Arc<dyn fjall::compaction::Strategy + Send + Sync> {
+        match self {
+            Self::Levelled {
+                target_size,
+                l0_threshold,
+            } => Arc::new(fjall::compaction::Levelled {
+                target_size: *target_size,
+                l0_threshold: *l0_threshold,
+            }),
+            Self::Tiered { base_size } => Arc::new(fjall::compaction::SizeTiered::new(*base_size)),
+            Self::Fifo { limit, ttl_secs } => {
+                Arc::new(fjall::compaction::Fifo::new(*limit, *ttl_secs))
+            }
+        }
+    }
+}
+
+/// Storage options of a locality group
+///
+/// Persisted in the table manifest and applied every time the table is opened.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LocalityGroupOptions {
+    /// Compaction strategy, defaults to levelled compaction
+    #[serde(default)]
+    pub compaction: Option<CompactionOptions>,
+
+    /// Block size, only applies when the locality group is created
+    #[serde(default)]
+    pub block_size: Option<u32>,
+
+    /// Maximum memtable size in bytes
+    #[serde(default)]
+    pub max_memtable_size: Option<u32>,
+}
+
 impl LocalityGroup {
     pub fn contains_column_family(&self, name: &str) -> bool {
         self.column_families.iter().any(|cf| &**cf == name)
@@ -65,6 +142,27 @@ pub struct SmoltableInner {
 
     /// User-defined locality groups
     pub locality_groups: RwLock<Vec<LocalityGroup>>,
+
+    /// Serializes conditional mutations and read-modify-writes per row
+    pub row_locks: RowLocks,
+
+    /// Held shared while cell mutations are committed, and exclusively while
+    /// column families are moved between locality groups
+    ///
+    /// Contains the partitions that writes to moved column families are mirrored into.
+    pub(crate) write_gate: RwLock<Vec<RegroupMirror>>,
+
+    /// Serializes regroups
+    pub(crate) regroup_lock: Mutex<()>,
+
+    /// Column families whose cells are being deleted, writes to them are rejected
+    pub(crate) deleting_column_families: RwLock<HashSet<Arc<str>>>,
+
+    /// Value types of the defined column families, cached from the manifest
+    pub column_families: RwLock<HashMap<Arc<str>, Option<ValueTypeDefinition>>>,
+
+    /// Whether writes are validated against the defined column families
+    pub schema_mode: RwLock<SchemaMode>,
 }
 
 /// A single smoltable
@@ -92,16 +190,58 @@ impl GarbageCollectionOptions {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+/// Handling of writes to column families that are not defined in the manifest
+///
+/// New tables are strict, tables created before schema enforcement
+/// existed are permissive.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub enum SchemaMode {
+    /// Writes to undefined column families are rejected
+    #[default]
+    #[serde(rename = "strict")]
+    Strict,
+
+    /// Writes to undefined column families are stored in the default locality group
+    #[serde(rename = "permissive")]
+    Permissive,
+}
+
+/// Value types the cells of a column family are constrained to
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ValueTypeDefinition {
+    /// Value type of all columns of the family
+    #[serde(default)]
+    pub family: Option<CellValueType>,
+
+    /// Value types of single columns, taking precedence over the family-wide value type
+    #[serde(default)]
+    pub qualifiers: BTreeMap<String, CellValueType>,
+}
+
+impl ValueTypeDefinition {
+    /// Returns the value type the column is constrained to, if any
+    pub fn get(&self, qualifier: &str) -> Option<CellValueType> {
+        self.qualifiers.get(qualifier).copied().or(self.family)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
 pub struct ColumnFamilyDefinition {
     pub name: String,
     pub gc_settings: GarbageCollectionOptions,
+
+    #[serde(default)]
+    pub value_type: Option<ValueTypeDefinition>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct CreateColumnFamilyInput {
     pub column_families: Vec<ColumnFamilyDefinition>,
     pub locality_group: Option<bool>,
+
+    /// Storage options of the locality group, if one is created
+    #[serde(default)]
+    pub locality_group_options: Option<LocalityGroupOptions>,
 }
 
 impl Smoltable {
@@ -112,6 +252,8 @@ impl Smoltable {
         keyspace: Keyspace,
         strategy: Arc<dyn fjall::compaction::Strategy + Send + Sync>,
     ) -> crate::Result<Smoltable> {
+        let is_new_table = !keyspace.partition_exists(&format!("_man_{name}"));
+
         let manifest = {
             let config = fjall::PartitionCreateOptions::default()
                 .level_count(2)
@@ -143,12 +285,19 @@ impl Smoltable {
             tree,
             manifest,
             locality_groups: RwLock::default(),
+            row_locks: RowLocks::new(ROW_LOCK_STRIPE_COUNT),
+            write_gate: RwLock::default(),
+            regroup_lock: Mutex::default(),
+            deleting_column_families: RwLock::default(),
+            column_families: RwLock::default(),
+            schema_mode: RwLock::default(),
         };
         let table = Self(Arc::new(table));
 
+        table.load_column_families()?;
+        table.load_schema_mode(is_new_table)?;
         table.load_locality_groups()?;
-
-        // TODO: set block cache(s) if defined
+        table.migrate_key_format()?;
 
         Ok(table)
     }
@@ -177,6 +326,67 @@ impl Smoltable {
             .unwrap_or_else(|| self.tree.clone()))
     }
 
+    /// Atomically commits cell mutations into the locality groups of their column families
+    pub(crate) fn commit_mutations(&self, mutations: Vec<CellMutation>) -> crate::Result<()> {
+        // NOTE: Hold the gate until the batch is committed, so column families
+        // cannot be moved to another locality group in the meantime
+        let mirrors = self.write_gate.read().expect("lock is poisoned");
+
+        {
+            let deleting_column_families = self
+                .deleting_column_families
+                .read()
+                .expect("lock is poisoned");
+
+            // NOTE: Deleted cells are purged in batches, so new cells of the column family
+            // could be partially deleted, even if the schema is not enforced
+            let deleted = mutations
+                .iter()
+                .filter(|mutation| mutation.value.is_some())
+                .map(|mutation| mutation.family.as_str())
+                .filter(|family| deleting_column_families.contains(*family))
+                .collect::<std::collections::BTreeSet<_>>();
+
+            if !deleted.is_empty() {
+                return Err(crate::Error::UndefinedColumnFamilies(
+                    deleted.into_iter().map(str::to_owned).collect(),
+                ));
+            }
+        }
+
+        let mut batch = self.batch();
+
+        for mutation in mutations {
+            let partition = self.get_partition_for_column_family(&mutation.family)?;
+
+            let mirror = mirrors
+                .iter()
+                .find(|mirror| mirror.is_mirrored(&mutation))
+                .map(|mirror| &mirror.partition);
+
+            match &mutation.value {
+                Some(value) => {
+                    if let Some(mirror) = mirror {
+                        batch.insert(mirror, &*mutation.key, value);
+                    }
+
+                    batch.insert(&partition, &*mutation.key, value);
+                }
+                None => {
+                    if let Some(mirror) = mirror {
+                        batch.remove(mirror, &*mutation.key);
+                    }
+
+                    batch.remove(&partition, &*mutation.key);
+                }
+            }
+        }
+
+        batch.commit()?;
+
+        Ok(())
+    }
+
     pub fn column_family_count(&self) -> crate::Result<usize> {
         let mut count = 0;
 
@@ -202,6 +412,140 @@ impl Smoltable {
         Ok(items)
     }
 
+    fn load_column_families(&self) -> crate::Result<()> {
+        let column_families = self
+            .list_column_families()?
+            .into_iter()
+            .map(|cf| (cf.name.into(), cf.value_type))
+            .collect();
+
+        *self.column_families.write().expect("lock is poisoned") = column_families;
+
+        Ok(())
+    }
+
+    /// Loads the schema mode, persisting it if the table has none yet
+    ///
+    /// Tables that were created before the schema mode existed accepted writes
+    /// to any column family, so they stay permissive.
+    fn load_schema_mode(&self, is_new_table: bool) -> crate::Result<()> {
+        let Some(value) = self.manifest.get("schema#mode")? else {
+            let mode = if is_new_table {
+                SchemaMode::Strict
+            } else {
+                SchemaMode::Permissive
+            };
+
+            return self.set_schema_mode(mode);
+        };
+
+        let value = std::str::from_utf8(&value).expect("should be utf-8");
+        let schema_mode = serde_json::from_str(value).expect("should deserialize");
+
+        *self.schema_mode.write().expect("lock is poisoned") = schema_mode;
+
+        Ok(())
+    }
+
+    pub fn schema_mode(&self) -> SchemaMode {
+        *self.schema_mode.read().expect("lock is poisoned")
+    }
+
+    /// Sets the schema mode.
+    ///
+    /// Will be persisted, no need to call after every restart.
+    pub fn set_schema_mode(&self, mode: SchemaMode) -> crate::Result<()> {
+        log::debug!("Setting schema mode {mode:?} for table {:?}", self.name);
+
+        let str = serde_json::to_string(&mode).expect("should serialize");
+        self.manifest.insert("schema#mode", str)?;
+        self.keyspace.persist(fjall::PersistMode::SyncAll)?;
+
+        *self.schema_mode.write().expect("lock is poisoned") = mode;
+
+        Ok(())
+    }
+
+    /// Checks that all written column families are defined, if the schema is enforced
+    ///
+    /// Returns [`Error::UndefinedColumnFamilies`](crate::Error::UndefinedColumnFamilies)
+    /// listing all offending column families otherwise.
+    pub fn check_column_families(&self, items: &[RowWriteItem]) -> crate::Result<()> {
+        if self.schema_mode() == SchemaMode::Permissive {
+            return Ok(());
+        }
+
+        let column_families = self.column_families.read().expect("lock is poisoned");
+
+        let undefined = items
+            .iter()
+            .flat_map(|item| &item.cells)
+            .map(|cell| cell.column_key.family.as_str())
+            .filter(|family| !column_families.contains_key(*family))
+            .collect::<std::collections::BTreeSet<_>>();
+
+        if undefined.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::Error::UndefinedColumnFamilies(
+                undefined.into_iter().map(str::to_owned).collect(),
+            ))
+        }
+    }
+
+    /// Checks that all written cells have the value types defined for their columns
+    ///
+    /// Returns [`Error::ValueTypeMismatch`](crate::Error::ValueTypeMismatch) otherwise.
+    pub fn check_value_types(&self, item: &RowWriteItem) -> crate::Result<()> {
+        let column_families = self.column_families.read().expect("lock is poisoned");
+
+        for cell in &item.cells {
+            let Some(Some(value_types)) = column_families.get(cell.column_key.family.as_str())
+            else {
+                continue;
+            };
+
+            let qualifier = cell.column_key.qualifier.as_deref().unwrap_or_default();
+
+            if let Some(value_type) = value_types.get(qualifier) {
+                if cell.value.value_type() != value_type {
+                    return Err(crate::Error::ValueTypeMismatch(
+                        cell.column_key.clone(),
+                        value_type,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn open_locality_group_partition(
+        &self,
+        id: &str,
+        options: &LocalityGroupOptions,
+    ) -> crate::Result<PartitionHandle> {
+        let tree = self.keyspace.open_partition(
+            &format!("_lg_{id}"),
+            fjall::PartitionCreateOptions::default()
+                .block_size(options.block_size.unwrap_or(BLOCK_SIZE)),
+        )?;
+
+        tree.set_compaction_strategy(match &options.compaction {
+            Some(compaction) => compaction.to_strategy(),
+            None => Arc::new(fjall::compaction::Levelled {
+                target_size: 64 * 1_024 * 1_024,
+                l0_threshold: 8,
+            }),
+        });
+
+        if let Some(bytes) = options.max_memtable_size {
+            tree.set_max_memtable_size(bytes);
+        }
+
+        Ok(tree)
+    }
+
     fn load_locality_groups(&self) -> crate::Result<()> {
         let items = self.manifest.prefix("lg#").collect::<Result<Vec<_>, _>>()?;
 
@@ -215,24 +559,21 @@ impl Smoltable {
 
                 let column_families = serde_json::from_str(value).expect("should deserialize");
 
-                log::debug!("Loading locality group {id} <= {:?}", column_families);
+                let options = match self.manifest.get(format!("lgopt#{id}"))? {
+                    Some(value) => {
+                        let value = std::str::from_utf8(&value).expect("should be utf-8");
+                        serde_json::from_str(value).expect("should deserialize")
+                    }
+                    None => LocalityGroupOptions::default(),
+                };
+
+                log::debug!("Loading locality group {id} <= {column_families:?} ({options:?})");
 
                 Ok(LocalityGroup {
                     id: id.into(),
                     column_families,
-                    tree: {
-                        let tree = self.keyspace.open_partition(
-                            &format!("_lg_{id}"),
-                            fjall::PartitionCreateOptions::default().block_size(BLOCK_SIZE),
-                        )?;
-
-                        tree.set_compaction_strategy(Arc::new(fjall::compaction::Levelled {
-                            target_size: 64 * 1_024 * 1_024,
-                            l0_threshold: 8,
-                        }));
-
-                        tree
-                    },
+                    tree: self.open_locality_group_partition(id, &options)?,
+                    options,
                 })
             })
             .collect::<crate::Result<Vec<_>>>()?;
@@ -242,21 +583,6 @@ impl Smoltable {
         Ok(())
     }
 
-    /*  /// Creates a dedicated block cache for the table.
-    ///
-    /// Will be applied after restart automatically, no need to call after every start.
-    pub fn set_cache_size(&self, bytes: u64) -> crate::Result<()> {
-        log::debug!("Setting block cache with {bytes}B table {:?}", self.name);
-
-        self.manifest.insert("cache#bytes", bytes.to_be_bytes())?;
-
-        // TODO: create block cache and apply to locality group...or all partitions...
-
-        self.keyspace.persist()?;
-
-        Ok(())
-    } */
-
     /// Creates column families.
     ///
     /// Will be persisted, no need to call after every restart.
@@ -286,16 +612,191 @@ impl Smoltable {
             let str = serde_json::to_string(&names).expect("should serialize");
 
             batch.insert(&self.manifest, format!("lg#{locality_group_id}"), str);
+
+            if let Some(options) = &input.locality_group_options {
+                let str = serde_json::to_string(options).expect("should serialize");
+                batch.insert(&self.manifest, format!("lgopt#{locality_group_id}"), str);
+            }
         }
 
         batch.commit()?;
         self.keyspace.persist(fjall::PersistMode::SyncAll)?;
 
+        self.load_column_families()?;
         self.load_locality_groups()?;
 
         Ok(())
     }
 
+    /// Updates the garbage collection settings of a column family.
+    ///
+    /// Will be persisted, no need to call after every restart.
+    pub fn update_column_family(
+        &self,
+        name: &str,
+        gc_settings: GarbageCollectionOptions,
+    ) -> crate::Result<()> {
+        log::debug!("Updating column family {name:?} for table {:?}", self.name);
+
+        let mut definition = self
+            .list_column_families()?
+            .into_iter()
+            .find(|cf| cf.name == name)
+            .ok_or_else(|| crate::Error::ColumnFamilyNotFound(name.to_owned()))?;
+
+        definition.gc_settings = gc_settings;
+
+        let str = serde_json::to_string(&definition).expect("should serialize");
+        self.manifest.insert(format!("cf#{name}"), str)?;
+        self.keyspace.persist(fjall::PersistMode::SyncAll)?;
+
+        self.load_column_families()?;
+
+        Ok(())
+    }
+
+    /// Deletes a column family and all its cells.
+    ///
+    /// The column family is removed from the manifest first, then its cells are
+    /// deleted in batches. Writes to the column family are rejected until all its
+    /// cells are deleted, even if the schema is not enforced.
+    ///
+    /// If the column family was the last one of its locality group,
+    /// the locality group is deleted as well.
+    pub fn delete_column_family(&self, name: &str) -> crate::Result<u64> {
+        log::debug!("Deleting column family {name:?} for table {:?}", self.name);
+
+        // NOTE: Column families may not be moved while their cells are deleted
+        let _regroup_lock = self.regroup_lock.lock().expect("lock is poisoned");
+
+        if !self
+            .column_families
+            .read()
+            .expect("lock is poisoned")
+            .contains_key(name)
+        {
+            return Err(crate::Error::ColumnFamilyNotFound(name.to_owned()));
+        }
+
+        let partition = self.get_partition_for_column_family(name)?;
+
+        let mut batch = self.keyspace.batch();
+        batch.remove(&self.manifest, format!("cf#{name}"));
+
+        let mut is_locality_group_deleted = false;
+
+        if let Some(locality_group) = self
+            .locality_groups
+            .read()
+            .expect("lock is poisoned")
+            .iter()
+            .find(|lg| lg.contains_column_family(name))
+        {
+            let names: Vec<Arc<str>> = locality_group
+                .column_families
+                .iter()
+                .filter(|cf| &***cf != name)
+                .cloned()
+                .collect();
+
+            let key = format!("lg#{}", locality_group.id);
+
+            if names.is_empty() {
+                batch.remove(&self.manifest, key);
+                batch.remove(&self.manifest, format!("lgopt#{}", locality_group.id));
+                is_locality_group_deleted = true;
+            } else {
+                let str = serde_json::to_string(&names).expect("should serialize");
+                batch.insert(&self.manifest, key, str);
+            }
+        }
+
+        {
+            // NOTE: Wait for running writes, so no cell is written after the column family is removed
+            let _mirrors = self.write_gate.write().expect("lock is poisoned");
+
+            batch.commit()?;
+            self.keyspace.persist(fjall::PersistMode::SyncAll)?;
+
+            self.load_column_families()?;
+            self.load_locality_groups()?;
+
+            self.deleting_column_families
+                .write()
+                .expect("lock is poisoned")
+                .insert(name.into());
+        }
+
+        let result = if is_locality_group_deleted {
+            // NOTE: The locality group only contained this column family, so drop it entirely
+            partition
+                .len()
+                .map_err(Into::into)
+                .and_then(|deleted_cells_count| {
+                    self.keyspace.delete_partition(partition)?;
+                    Ok(deleted_cells_count as u64)
+                })
+        } else {
+            self.delete_column_family_cells(&partition, name)
+        };
+
+        self.deleting_column_families
+            .write()
+            .expect("lock is poisoned")
+            .remove(name);
+
+        result
+    }
+
+    /// Deletes all cells of a column family from a partition, in batches
+    fn delete_column_family_cells(
+        &self,
+        partition: &PartitionHandle,
+        name: &str,
+    ) -> crate::Result<u64> {
+        use reader::Reader as TableReader;
+        use std::ops::Bound;
+
+        let mut cursor = Bound::Unbounded;
+        let mut deleted_cells_count = 0;
+
+        loop {
+            let mut reader = TableReader::new(self.keyspace.instant(), partition.clone(), cursor);
+
+            let mut batch = self.keyspace.batch();
+            let mut batch_cells_count = 0;
+            let mut last_key = None;
+
+            for cell in &mut reader {
+                let cell = cell?;
+
+                if cell.column_key.family == name {
+                    batch.remove(partition, &*cell.raw_key);
+                    batch_cells_count += 1;
+                    deleted_cells_count += 1;
+                }
+
+                last_key = Some(cell.raw_key);
+
+                if batch_cells_count >= DELETE_BATCH_SIZE {
+                    break;
+                }
+            }
+
+            batch.commit()?;
+
+            match last_key {
+                Some(key) if batch_cells_count >= DELETE_BATCH_SIZE => {
+                    cursor = Bound::Excluded(key);
+                }
+                _ => {
+                    self.keyspace.persist(fjall::PersistMode::SyncAll)?;
+                    return Ok(deleted_cells_count);
+                }
+            }
+        }
+    }
+
     pub fn approximate_cell_count(&self) -> crate::Result<u64> {
         let locality_groups = get_affected_locality_groups(self, &None)?;
 
@@ -340,11 +841,8 @@ impl Smoltable {
         let mut current_row_key = None;
 
         let mut reader = MergeReader::new(readers);
-        loop {
-            let Some(cell) = (&mut reader).next() else {
-                break;
-            };
 
+        for cell in &mut reader {
             let cell = cell?;
 
             cell_count += 1;
@@ -358,95 +856,138 @@ impl Smoltable {
         Ok((row_count, cell_count))
     }
 
-    pub fn scan_count(&self, input: CountInput) -> crate::Result<CountOutput> {
-        use reader::Reader as TableReader;
-
-        let column_filter = &input.column.as_ref().and_then(|x| x.filter.clone());
+    /// Lazily visits all cells of a scan that match its column filter, time range and row filter
+    ///
+    /// Cells are not grouped into rows, and no row or cell limits are applied.
+    pub fn scan_cells(&self, input: CountInput) -> crate::Result<CellIterator> {
+        CellIterator::new(
+            self,
+            self.keyspace.instant(),
+            &input.row.scan,
+            None,
+            input.column.and_then(|x| x.filter),
+            input.cell.and_then(|x| x.time),
+            input.filter,
+        )
+    }
 
-        let locality_groups_to_scan = get_affected_locality_groups(self, column_filter)?;
-        let instant = self.keyspace.instant();
+    pub fn scan_count(&self, input: CountInput) -> crate::Result<CountOutput> {
+        let mut cells = self.scan_cells(input)?;
 
-        let mut bytes_scanned_count: u64 = 0;
         let mut cell_count = 0; // Cell count over all aggregated rows
 
         let mut current_row_key: Option<String> = None;
         let mut row_count = 0;
 
-        let affected_locality_groups = locality_groups_to_scan.len();
+        for cell in &mut cells {
+            let cell = cell?;
 
-        let readers = locality_groups_to_scan
-            .into_iter()
-            .map(|locality_group| match &input.row.scan {
-                ScanMode::Prefix(prefix) => {
-                    TableReader::from_prefix(instant, locality_group, prefix)
-                }
-                ScanMode::Range(range) => {
-                    TableReader::from_prefix(instant, locality_group, &range.start)
-                } // TODO: ScanMode::Ranges(ranges) => unimplemented!(),
-            })
-            .collect::<fjall::Result<Vec<_>>>()?
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
+            if current_row_key.as_ref() != Some(&cell.row_key) {
+                current_row_key = Some(cell.row_key);
 
-        let mut reader = MergeReader::new(readers);
+                // We are visiting a new row
+                row_count += 1;
+            }
 
-        let mut should_be_terminated = false;
+            cell_count += 1;
+        }
 
-        loop {
-            let Some(cell) = (&mut reader).next() else {
-                break;
-            };
+        Ok(CountOutput {
+            affected_locality_groups: cells.affected_locality_groups(),
+            cell_count,
+            row_count,
+            bytes_scanned_count: cells.bytes_scanned_count(),
+        })
+    }
+
+    /// Aggregates numeric cell values per column, optionally grouped by row key
+    ///
+    /// Cells that are not numeric are skipped.
+    pub fn aggregate(&self, input: AggregateInput) -> crate::Result<AggregateOutput> {
+        let is_all_versions = input.cell.as_ref().is_some_and(|x| x.all_versions);
+        let group_limit = input.row.group_limit.unwrap_or(u32::MAX) as usize;
+
+        let mut cells = CellIterator::new(
+            self,
+            self.keyspace.instant(),
+            &input.row.scan,
+            None,
+            input.column.and_then(|x| x.filter),
+            input.cell.and_then(|x| x.time),
+            input.filter,
+        )?;
+
+        let mut groups: BTreeMap<String, AggregateGroup> = BTreeMap::new();
 
+        let mut cell_count = 0;
+        let mut row_count = 0;
+        let mut is_group_limit_reached = false;
+
+        let mut current_row_key: Option<String> = None;
+        let mut last_column: Option<(String, ColumnKey)> = None;
+
+        for cell in &mut cells {
             let cell = cell?;
 
-            match &input.row.scan {
-                ScanMode::Prefix(prefix) => {
-                    if !cell.row_key.starts_with(prefix) {
-                        should_be_terminated = true;
-                        continue;
-                    }
-                }
-                ScanMode::Range(range) => {
-                    if range.inclusive {
-                        if cell.row_key > range.end {
-                            should_be_terminated = true;
-                            continue;
-                        }
-                    } else if cell.row_key >= range.end {
-                        should_be_terminated = true;
-                        continue;
-                    }
+            // NOTE: Versions of a column are visited from newest to oldest,
+            // so only the first visited version is the latest
+            if !is_all_versions {
+                if last_column.as_ref().is_some_and(|(row_key, column_key)| {
+                    *row_key == cell.row_key && *column_key == cell.column_key
+                }) {
+                    continue;
                 }
+
+                last_column = Some((cell.row_key.clone(), cell.column_key.clone()));
             }
 
-            if let Some(filter) = column_filter {
-                if !cell.satisfies_column_filter(filter) {
-                    continue;
-                }
+            if cell.value.as_f64().is_none() {
+                continue;
             }
 
-            if current_row_key.is_none() || current_row_key.as_ref().unwrap() != &cell.row_key {
+            let group_key = input.row.group_by.as_ref().map_or("", |group_by| {
+                group_by.group_key(&cell.row_key, &input.row.scan)
+            });
+
+            // NOTE: Groups are contiguous in row key order, so no more cells of
+            // the returned groups can follow
+            if !groups.contains_key(group_key) && groups.len() >= group_limit {
+                is_group_limit_reached = true;
+                break;
+            }
+
+            let group = groups
+                .entry(group_key.to_owned())
+                .or_insert_with_key(|key| AggregateGroup {
+                    key: key.clone(),
+                    row_count: 0,
+                    columns: BTreeMap::new(),
+                });
+
+            group
+                .columns
+                .entry(cell.column_key.to_string())
+                .or_default()
+                .push(cell.timestamp, cell.value);
+
+            if current_row_key.as_ref() != Some(&cell.row_key) {
                 current_row_key = Some(cell.row_key);
 
                 // We are visiting a new row
+                group.row_count += 1;
                 row_count += 1;
-
-                if should_be_terminated {
-                    break;
-                }
             }
 
             cell_count += 1;
         }
 
-        bytes_scanned_count += reader.bytes_scanned_count();
-
-        Ok(CountOutput {
-            affected_locality_groups,
-            cell_count: cell_count as u64,
-            row_count: row_count as u64,
-            bytes_scanned_count,
+        Ok(AggregateOutput {
+            affected_locality_groups: cells.affected_locality_groups(),
+            groups: groups.into_values().collect(),
+            cell_count,
+            row_count,
+            bytes_scanned_count: cells.bytes_scanned_count(),
+            is_group_limit_reached,
         })
     }
 
@@ -508,11 +1049,7 @@ impl Smoltable {
                 reader.partition.name
             );
 
-            loop {
-                let Some(cell) = reader.next() else {
-                    break;
-                };
-
+            while let Some(cell) = reader.next() {
                 let cell = cell?;
 
                 if current_row_key.is_none() || current_row_key.clone().unwrap() != cell.row_key {
@@ -563,243 +1100,483 @@ impl Smoltable {
 
     // TODO: delete row thrashes block cache
 
-    // TODO: allow deleting specific columns -> DeleteRowInput, also batch + limit it?
     pub fn delete_row(
         &self,
         row_key: String,
         column_filter: Option<ColumnFilter>,
     ) -> crate::Result<u64> {
-        let mut count = 0;
+        self.delete_row_filtered(row_key, column_filter, None)
+    }
 
-        let mut reader = SingleRowReader::new(
-            self,
-            self.keyspace.instant(),
-            QueryRowInput {
-                row: QueryRowInputRowOptions {
-                    key: row_key,
-                    cell_limit: None,
-                },
-                column: column_filter.map(|cf| QueryRowColumnOptions {
-                    cell_limit: None,
-                    filter: Some(cf),
-                }),
+    /// Deletes the cells of a row that match the column filter and row filter
+    pub fn delete_row_filtered(
+        &self,
+        row_key: String,
+        column_filter: Option<ColumnFilter>,
+        filter: Option<RowFilter>,
+    ) -> crate::Result<u64> {
+        let result = self.delete_rows(DeleteInput {
+            row: DeleteRowOptions {
+                mode: DeleteMode::Keys(vec![row_key]),
+                cell_limit: None,
+                cursor: None,
             },
-        )?;
-
-        for cell in &mut reader {
-            let cell = cell?;
-            self.tree.remove(&cell.raw_key)?;
+            column_filter,
+            filter,
+        })?;
 
-            log::trace!("Deleted cell {:?}", cell.raw_key);
-            count += 1;
-        }
-
-        Ok(count)
+        Ok(result.deleted_cells_count)
     }
 
-    pub fn multi_get(&self, inputs: Vec<QueryRowInput>) -> crate::Result<QueryPrefixOutput> {
-        let mut cells_scanned_count = 0;
-        let mut rows_scanned_count = 0;
-        let mut bytes_scanned_count = 0;
+    /// Deletes a list of rows, a row prefix or a row range
+    ///
+    /// All deletes are committed as one atomic batch.
+    /// If a cell limit is set, only the first batch is deleted
+    /// and a cursor to continue with is returned.
+    pub fn delete_rows(&self, input: DeleteInput) -> crate::Result<DeleteOutput> {
+        let cell_limit = input.row.cell_limit.unwrap_or(u32::MAX) as u64;
+        let cursor = input.row.cursor.as_deref();
+        let instant = self.keyspace.instant();
 
-        let mut rows = Vec::with_capacity(inputs.len());
+        let mut mutations = vec![];
+        let mut deleted_cells_count = 0;
+        let mut next_cursor = None;
 
-        let mut affected_locality_groups = 0;
+        let mut row_filter = FilterEvaluator::new(input.filter.clone());
 
-        for input in inputs {
-            let query_result = self.get_row(input)?;
-            rows.extend(query_result.row);
+        let scan = match &input.row.mode {
+            DeleteMode::Keys(_) => None,
+            DeleteMode::Prefix(prefix) => Some(ScanMode::Prefix(prefix.clone())),
+            DeleteMode::Range(range) => Some(ScanMode::Range(range.clone())),
+        };
 
-            affected_locality_groups += query_result.affected_locality_groups;
-            cells_scanned_count += query_result.cells_scanned_count;
-            bytes_scanned_count += query_result.bytes_scanned_count;
-            rows_scanned_count += 1;
-        }
+        if let Some(scan) = scan {
+            let locality_groups = get_affected_locality_groups(self, &input.column_filter)?;
 
-        Ok(QueryPrefixOutput {
-            rows,
-            affected_locality_groups,
-            rows_scanned_count,
-            cells_scanned_count,
-            bytes_scanned_count,
-        })
-    }
+            // NOTE: Already deleted cells are not visited again,
+            // so we can continue at the start of the cursor row
+            let resume_after: Option<Arc<[u8]>> =
+                cursor.map(|cursor| crate::key::escape(cursor.as_bytes()).into());
 
-    // TODO: use in get_row and query_prefix/scan: RowGatherer that gets some Readers and... gathers them
+            let readers =
+                Self::get_scan_readers(instant, &locality_groups, &scan, resume_after.as_ref())?;
 
-    pub fn scan(&self, input: QueryPrefixInput) -> crate::Result<QueryPrefixOutput> {
-        use reader::Reader as TableReader;
+            let mut reader = MergeReader::new(readers);
 
-        let column_filter = &input.column.as_ref().and_then(|x| x.filter.clone());
+            for cell in &mut reader {
+                let cell = cell?;
 
-        let row_offset = input.row.offset.unwrap_or_default() as u64;
-        let row_limit = input.row.limit.unwrap_or(u32::from(u16::MAX)) as usize;
+                if scan.is_out_of_bounds(&cell.row_key) {
+                    break;
+                }
 
-        let column_cell_limit = input
-            .column
-            .as_ref()
-            .and_then(|x| x.cell_limit)
-            .unwrap_or(u32::from(u16::MAX)) as usize;
+                if let Some(filter) = &input.column_filter {
+                    if !cell.satisfies_column_filter(filter) {
+                        continue;
+                    }
+                }
 
-        let row_cell_limit = input.row.cell_limit.unwrap_or(u32::from(u16::MAX)) as usize;
+                if !row_filter.matches(&cell) {
+                    continue;
+                }
 
-        let global_cell_limit = input
-            .cell
-            .as_ref()
-            .and_then(|x| x.limit)
-            .unwrap_or(u32::from(u16::MAX)) as usize;
+                if deleted_cells_count >= cell_limit {
+                    next_cursor = Some(cell.row_key);
+                    break;
+                }
 
-        let locality_groups_to_scan = get_affected_locality_groups(self, column_filter)?;
-        let instant = self.keyspace.instant();
+                log::trace!("Deleted cell {:?}", cell.raw_key);
+                deleted_cells_count += 1;
 
-        let mut rows_scanned_count: u64 = 0;
-        let mut cells_scanned_count: u64 = 0;
-        let mut bytes_scanned_count: u64 = 0;
-        let mut cell_count = 0; // Cell count over all aggregated rows
+                mutations.push(CellMutation {
+                    family: cell.column_key.family,
+                    key: cell.raw_key,
+                    value: None,
+                });
+            }
+        } else if let DeleteMode::Keys(row_keys) = &input.row.mode {
+            let mut row_keys = row_keys.iter().collect::<Vec<_>>();
+            row_keys.sort();
+            row_keys.dedup();
 
-        let mut row_sample_counter = 1.0_f32;
+            'rows: for row_key in row_keys {
+                if cursor.is_some_and(|cursor| row_key.as_str() < cursor) {
+                    continue;
+                }
 
-        let mut rows: BTreeMap<String, Row> = BTreeMap::new();
+                let mut reader = SingleRowReader::new(
+                    self,
+                    instant,
+                    QueryRowInput {
+                        row: QueryRowInputRowOptions {
+                            key: row_key.clone(),
+                            cell_limit: None,
+                        },
+                        column: input.column_filter.clone().map(|cf| QueryRowColumnOptions {
+                            cell_limit: None,
+                            limit: None,
+                            start_after: None,
+                            filter: Some(cf),
+                        }),
+                        cell: None,
+                        filter: None,
+                    },
+                )?;
 
-        let affected_locality_groups = locality_groups_to_scan.len();
+                for cell in &mut reader {
+                    let cell = cell?;
 
-        let readers = locality_groups_to_scan
-            .into_iter()
-            .map(|locality_group| match &input.row.scan {
-                ScanMode::Prefix(prefix) => {
-                    TableReader::from_prefix(instant, locality_group, prefix)
-                }
-                ScanMode::Range(range) => {
-                    TableReader::from_prefix(instant, locality_group, &range.start)
-                } // TODO: ScanMode::Ranges(ranges) => unimplemented!(),
-            })
-            .collect::<fjall::Result<Vec<_>>>()?
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>();
+                    if !row_filter.matches(&cell) {
+                        continue;
+                    }
 
-        let mut reader = MergeReader::new(readers);
+                    if deleted_cells_count >= cell_limit {
+                        next_cursor = Some(row_key.clone());
+                        break 'rows;
+                    }
 
-        let mut should_be_terminated = false;
+                    log::trace!("Deleted cell {:?}", cell.raw_key);
+                    deleted_cells_count += 1;
 
-        loop {
-            // We are gonna visit another cell, if the global cell limit is reached
-            // we can short circuit out of the loop
-            if cell_count >= global_cell_limit {
-                break;
+                    mutations.push(CellMutation {
+                        family: cell.column_key.family,
+                        key: cell.raw_key,
+                        value: None,
+                    });
+                }
             }
+        }
 
-            let Some(cell) = (&mut reader).next() else {
-                break;
-            };
+        self.commit_mutations(mutations)?;
 
-            let cell = cell?;
+        Ok(DeleteOutput {
+            deleted_cells_count,
+            cursor: next_cursor,
+        })
+    }
 
-            match &input.row.scan {
-                ScanMode::Prefix(prefix) => {
-                    if !cell.row_key.starts_with(prefix) {
-                        should_be_terminated = true;
-                        continue;
-                    }
-                }
-                ScanMode::Range(range) => {
-                    if range.inclusive {
-                        if cell.row_key > range.end {
-                            should_be_terminated = true;
-                            continue;
-                        }
-                    } else if cell.row_key >= range.end {
-                        should_be_terminated = true;
-                        continue;
-                    }
-                }
-            }
+    /// Atomically applies one of two lists of mutations to a row,
+    /// depending on whether the predicate matches the latest version of a column
+    ///
+    /// Conditional mutations, read-modify-writes and writes through [`TableWriter`](crate::TableWriter)
+    /// on the same row are serialized. Deletes through [`Smoltable::delete_rows`] are not.
+    pub fn check_and_mutate(
+        &self,
+        input: CheckAndMutateInput,
+    ) -> crate::Result<CheckAndMutateOutput> {
+        let _row_lock = self.row_locks.lock(&input.row_key);
 
-            if let Some(filter) = column_filter {
-                if !cell.satisfies_column_filter(filter) {
-                    continue;
-                }
-            }
+        let column_key = input.predicate.column_key();
 
-            if !rows.contains_key(&cell.row_key) {
-                // We are visiting a new row
-                rows_scanned_count += 1;
+        let query_result = self.get_row(QueryRowInput {
+            row: QueryRowInputRowOptions {
+                key: input.row_key.clone(),
+                cell_limit: None,
+            },
+            column: Some(QueryRowColumnOptions {
+                cell_limit: Some(1),
+                limit: None,
+                start_after: None,
+                filter: Some(ColumnFilter::Key(column_key.clone())),
+            }),
+            cell: None,
+            filter: None,
+        })?;
 
-                rows.retain(|_, row| row.column_count() > 0);
+        let latest_value = query_result
+            .row
+            .as_ref()
+            .and_then(|row| row.columns.get(&column_key.family))
+            .and_then(|family| family.get(column_key.qualifier.as_deref().unwrap_or_default()))
+            .and_then(|versions| versions.first())
+            .map(|cell| &cell.value);
 
-                // If the row limit is reached
-                // we can short circuit out of the loop
-                if rows.len() == row_limit {
-                    break;
-                }
+        let predicate_matched = input.predicate.matches(latest_value);
 
-                if let Some(sample_rate) = input.row.sample {
-                    if sample_rate < 1.0 {
-                        row_sample_counter += sample_rate;
+        let (cells, deletes) = if predicate_matched {
+            (input.true_mutations, input.true_deletes)
+        } else {
+            (input.false_mutations, input.false_deletes)
+        };
 
-                        if row_sample_counter < 1.0 {
-                            continue;
-                        } else {
-                            row_sample_counter -= 1.0;
-                        }
-                    }
-                }
+        if !cells.is_empty() || !deletes.is_empty() {
+            writer::Writer::write_batch_locked(
+                self.clone(),
+                &[RowWriteItem {
+                    row_key: input.row_key,
+                    cells,
+                    deletes,
+                }],
+            )?;
+        }
 
-                if should_be_terminated {
-                    break;
-                }
-            }
+        Ok(CheckAndMutateOutput { predicate_matched })
+    }
 
-            // TODO: test
-            if rows_scanned_count < row_offset {
-                continue;
-            }
+    /// Atomically modifies the latest values of columns in a row,
+    /// returning the new values
+    ///
+    /// Read-modify-writes, conditional mutations and writes through
+    /// [`TableWriter`](crate::TableWriter) on the same row are serialized.
+    pub fn read_modify_write(
+        &self,
+        input: ReadModifyWriteInput,
+    ) -> crate::Result<ReadModifyWriteOutput> {
+        let _row_lock = self.row_locks.lock(&input.row_key);
 
-            // IMPORTANT: Even if the row has no matching columns, we need to temporarily add it to
-            // the buffer, so we can track in which row we are currently in (to increment `rows_scanned_count`)
-            // After that it gets removed, if the column count stays 0
-            let row = rows.entry(cell.row_key).or_insert_with_key(|key| Row {
-                row_key: key.clone(),
-                columns: HashMap::default(),
-            });
+        let mut row = Row {
+            row_key: input.row_key.clone(),
+            columns: HashMap::default(),
+        };
 
-            if row.cell_count() >= row_cell_limit {
-                continue;
-            }
+        let mut modified_column_keys = Vec::with_capacity(input.rules.len());
+        let now = timestamp_nano();
 
-            let version_history = row
+        for rule in input.rules {
+            let family = &rule.column_key.family;
+            let qualifier = rule.column_key.qualifier.clone().unwrap_or_default();
+
+            let new_versions = row
                 .columns
-                .entry(cell.column_key.family)
+                .entry(family.clone())
                 .or_default()
-                .entry(cell.column_key.qualifier.unwrap_or_default())
+                .entry(qualifier.clone())
                 .or_default();
 
-            if version_history.len() >= column_cell_limit {
+            // NOTE: Rules on the same column compose, so only the final value is written
+            if let Some(cell) = new_versions.first_mut() {
+                let Some(value) = rule.operation.apply(Some(&cell.value)) else {
+                    return Err(crate::Error::InvalidValueType(rule.column_key));
+                };
+
+                cell.value = value;
                 continue;
             }
 
-            version_history.push(Cell {
-                timestamp: cell.timestamp,
-                value: cell.value,
-            });
+            let latest = self.get_latest_cell(&input.row_key, family, &qualifier)?;
 
-            cell_count += 1;
+            let Some(value) = rule
+                .operation
+                .apply(latest.as_ref().map(|cell| &cell.value))
+            else {
+                return Err(crate::Error::InvalidValueType(rule.column_key));
+            };
+
+            // NOTE: The new version needs to be newer than the latest version,
+            // even if that was written with a timestamp in the future
+            let timestamp = latest.map_or(now, |cell| now.max(cell.timestamp.saturating_add(1)));
+
+            new_versions.push(Cell { timestamp, value });
+            modified_column_keys.push(rule.column_key);
         }
 
-        cells_scanned_count += reader.cells_scanned_count();
-        bytes_scanned_count += reader.bytes_scanned_count();
+        let cells = modified_column_keys
+            .into_iter()
+            .map(|column_key| {
+                let cell = row
+                    .columns
+                    .get(&column_key.family)
+                    .and_then(|family| {
+                        family.get(column_key.qualifier.as_deref().unwrap_or_default())
+                    })
+                    .and_then(|versions| versions.first())
+                    .expect("modified column should exist");
+
+                ColumnWriteItem {
+                    column_key,
+                    timestamp: Some(cell.timestamp),
+                    value: cell.value.clone(),
+                }
+            })
+            .collect();
+
+        writer::Writer::write_batch_locked(
+            self.clone(),
+            &[RowWriteItem {
+                row_key: input.row_key,
+                cells,
+                deletes: vec![],
+            }],
+        )?;
+
+        Ok(ReadModifyWriteOutput { row })
+    }
+
+    /// Reads only the latest version of a single column
+    fn get_latest_cell(
+        &self,
+        row_key: &str,
+        family: &str,
+        qualifier: &str,
+    ) -> crate::Result<Option<Cell>> {
+        // NOTE: The row cell limit stops the reader after the first version,
+        // so the rest of the version history is never read
+        let query_result = self.get_row(QueryRowInput {
+            row: QueryRowInputRowOptions {
+                key: row_key.to_owned(),
+                cell_limit: Some(1),
+            },
+            column: Some(QueryRowColumnOptions {
+                cell_limit: Some(1),
+                limit: None,
+                start_after: None,
+                filter: Some(ColumnFilter::Key(ColumnKey {
+                    family: family.to_owned(),
+                    qualifier: Some(qualifier.to_owned()),
+                })),
+            }),
+            cell: None,
+            filter: None,
+        })?;
+
+        Ok(query_result
+            .row
+            .and_then(|mut row| row.columns.remove(family))
+            .and_then(|mut family| family.remove(qualifier))
+            .and_then(|versions| versions.into_iter().next()))
+    }
+
+    pub fn multi_get(&self, inputs: Vec<QueryRowInput>) -> crate::Result<QueryPrefixOutput> {
+        let mut cells_scanned_count = 0;
+        let mut rows_scanned_count = 0;
+        let mut bytes_scanned_count = 0;
 
-        rows.retain(|_, row| row.column_count() > 0);
+        let mut rows = Vec::with_capacity(inputs.len());
+
+        let mut affected_locality_groups = 0;
+
+        for input in inputs {
+            let query_result = self.get_row(input)?;
+            rows.extend(query_result.row);
+
+            affected_locality_groups += query_result.affected_locality_groups;
+            cells_scanned_count += query_result.cells_scanned_count;
+            bytes_scanned_count += query_result.bytes_scanned_count;
+            rows_scanned_count += 1;
+        }
 
         Ok(QueryPrefixOutput {
-            rows: rows.into_values().collect(),
+            rows,
             affected_locality_groups,
-            cells_scanned_count,
             rows_scanned_count,
+            cells_scanned_count,
             bytes_scanned_count,
+            continuation: None,
+            column_cursor: None,
         })
     }
 
+    /// Creates a reader for every locality group, starting at the
+    /// beginning of the given (single-range) scan mode
+    ///
+    /// If a `resume_after` key is given, the readers will not start before it.
+    fn get_scan_readers(
+        instant: fjall::Instant,
+        locality_groups: &[PartitionHandle],
+        scan: &ScanMode,
+        resume_after: Option<&Arc<[u8]>>,
+    ) -> crate::Result<Vec<reader::Reader>> {
+        use reader::Reader as TableReader;
+        use std::ops::Bound::{Excluded, Included};
+
+        let readers = match scan {
+            ScanMode::Prefix(prefix) => {
+                let prefix = crate::key::escape(prefix.as_bytes());
+
+                match resume_after {
+                    Some(key) if **key >= *prefix => locality_groups
+                        .iter()
+                        .cloned()
+                        .map(|locality_group| {
+                            TableReader::new(instant, locality_group, Excluded(key.clone()))
+                        })
+                        .collect(),
+                    _ => locality_groups
+                        .iter()
+                        .cloned()
+                        .map(|locality_group| {
+                            TableReader::from_prefix(instant, locality_group, &prefix)
+                        })
+                        .collect::<fjall::Result<Vec<_>>>()?
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                }
+            }
+            ScanMode::Range(range) => {
+                let start: Arc<[u8]> = crate::key::escape(range.start.as_bytes()).into();
+
+                let range_start = match resume_after {
+                    Some(key) if *key >= start => Excluded(key.clone()),
+                    _ => Included(start),
+                };
+
+                locality_groups
+                    .iter()
+                    .cloned()
+                    .map(|locality_group| {
+                        TableReader::new(instant, locality_group, range_start.clone())
+                    })
+                    .collect()
+            }
+            ScanMode::Ranges(_) => unreachable!("ranges should be split before scanning"),
+        };
+
+        Ok(readers)
+    }
+
+    // TODO: use in get_row and query_prefix/scan: RowGatherer that gets some Readers and... gathers them
+
+    pub fn scan(&self, input: QueryPrefixInput) -> crate::Result<QueryPrefixOutput> {
+        let mut rows = vec![];
+
+        let stats = self.scan_each(input, |row| {
+            rows.push(row);
+            true
+        })?;
+
+        Ok(QueryPrefixOutput {
+            rows,
+            affected_locality_groups: stats.affected_locality_groups,
+            cells_scanned_count: stats.cells_scanned_count,
+            rows_scanned_count: stats.rows_scanned_count,
+            bytes_scanned_count: stats.bytes_scanned_count,
+            continuation: stats.continuation,
+            column_cursor: stats.column_cursor,
+        })
+    }
+
+    /// Scans rows like [`Smoltable::scan`], but passes every row to the callback
+    /// as soon as it is complete, instead of collecting all rows
+    ///
+    /// Rows are passed in ascending order of their row key.
+    /// If the callback returns `false`, the scan is stopped.
+    pub fn scan_each(
+        &self,
+        input: QueryPrefixInput,
+        mut on_row: impl FnMut(Row) -> bool,
+    ) -> crate::Result<QueryPrefixStats> {
+        let mut rows = self.scan_rows(input)?;
+
+        for row in &mut rows {
+            if !on_row(row?) {
+                break;
+            }
+        }
+
+        Ok(rows.stats())
+    }
+
+    /// Scans rows like [`Smoltable::scan`], but returns an iterator that
+    /// lazily reads one row at a time
+    ///
+    /// Only the row that is currently visited is buffered, so the iterator
+    /// can be dropped at any time to stop the scan.
+    /// Use [`RowIterator::stats`] to get the scan statistics (and continuation token).
+    pub fn scan_rows(&self, input: QueryPrefixInput) -> crate::Result<RowIterator> {
+        RowIterator::new(self, input)
+    }
+
     fn column_families_in_default_locality_group(&self) -> crate::Result<Vec<String>> {
         let mut fams = self
             .list_column_families()?
@@ -830,9 +1607,21 @@ impl Smoltable {
             .and_then(|x| x.cell_limit)
             .unwrap_or(u32::from(u16::MAX));
 
+        let column_limit = input
+            .column
+            .as_ref()
+            .and_then(|x| x.limit)
+            .unwrap_or(u32::MAX);
+
         let row_key = input.row.key.clone();
         let mut columns: HashMap<String, HashMap<String, Vec<Cell>>> = HashMap::new();
 
+        let mut column_count = 0;
+        let mut current_column_key: Option<ColumnKey> = None;
+        let mut column_cursor = None;
+
+        let mut row_filter = FilterEvaluator::new(input.filter.clone());
+
         let mut reader = SingleRowReader::new(self, self.keyspace.instant(), input)?;
         let locality_group_count = reader.locality_group_count();
 
@@ -848,6 +1637,21 @@ impl Smoltable {
 
             let cell = cell?;
 
+            if !row_filter.matches(&cell) {
+                continue;
+            }
+
+            if current_column_key.as_ref() != Some(&cell.column_key) {
+                // We are visiting a new column
+                if column_count >= column_limit {
+                    column_cursor = current_column_key;
+                    break;
+                }
+
+                column_count += 1;
+                current_column_key = Some(cell.column_key.clone());
+            }
+
             // Append cell
             let version_history = columns
                 .entry(cell.column_key.family)
@@ -878,6 +1682,7 @@ impl Smoltable {
             affected_locality_groups: locality_group_count,
             cells_scanned_count: reader.cells_scanned_count(),
             bytes_scanned_count: reader.bytes_scanned_count(),
+            column_cursor,
         })
     }
 
@@ -13,6 +13,9 @@ pub struct Reader {
 
     buffer: VecDeque<VisitedCell>,
 
+    /// Key of a corrupt cell that follows the buffered cells
+    corrupt_key: Option<Arc<[u8]>>,
+
     pub cells_scanned_count: u64,
     pub bytes_scanned_count: u64,
 
@@ -32,6 +35,7 @@ impl Reader {
             snapshot,
             current_range_start: range,
             buffer: VecDeque::with_capacity(1_000),
+            corrupt_key: None,
             cells_scanned_count: 0,
             bytes_scanned_count: 0,
             chunk_size: 10,
@@ -46,7 +50,7 @@ impl Reader {
     pub fn from_prefix(
         instant: fjall::Instant,
         locality_group: PartitionHandle,
-        prefix: &str,
+        prefix: &[u8],
     ) -> fjall::Result<Option<Self>> {
         let Some(range) = Self::get_range_start_from_prefix(instant, &locality_group, prefix)?
         else {
@@ -63,10 +67,10 @@ impl Reader {
     pub fn get_range_start_from_prefix(
         instant: fjall::Instant,
         locality_group: &PartitionHandle,
-        prefix: &str,
+        prefix: &[u8],
     ) -> fjall::Result<Option<Arc<[u8]>>> {
         let snapshot = locality_group.snapshot_at(instant);
-        let item = snapshot.prefix(prefix.as_bytes()).next();
+        let item = snapshot.prefix(prefix).next();
 
         match item {
             Some(item) => {
@@ -77,8 +81,46 @@ impl Reader {
         }
     }
 
+    /// Skips all cells before the given key bound
+    ///
+    /// The bound needs to be after the last visited cell.
+    pub fn seek(&mut self, start: Bound<Arc<[u8]>>) {
+        use std::ops::Bound::{Excluded, Included, Unbounded};
+
+        while let Some(cell) = self.buffer.front() {
+            let is_before_start = match &start {
+                Included(key) => cell.raw_key < *key,
+                Excluded(key) => cell.raw_key <= *key,
+                Unbounded => false,
+            };
+
+            if !is_before_start {
+                // NOTE: The rest of the buffer is still valid
+                return;
+            }
+
+            self.buffer.pop_front();
+        }
+
+        if let Some(corrupt_key) = &self.corrupt_key {
+            let is_before_start = match &start {
+                Included(key) => corrupt_key < key,
+                Excluded(key) => corrupt_key <= key,
+                Unbounded => false,
+            };
+
+            if !is_before_start {
+                return;
+            }
+
+            self.corrupt_key = None;
+        }
+
+        self.current_range_start = start;
+    }
+
     // TODO: try to make Peek return a &smoltable::VisitedCell
-    pub fn peek(&mut self) -> Option<fjall::Result<VisitedCell>> {
+    pub fn peek(&mut self) -> Option<crate::Result<VisitedCell>> {
         use std::ops::Bound::{Excluded, Unbounded};
 
         // First, consume buffer, if filled
@@ -86,6 +128,12 @@ impl Reader {
             return Some(Ok(cell));
         }
 
+        // NOTE: The corrupt cell is skipped after returning the error,
+        // so the caller may decide to continue reading
+        if let Some(corrupt_key) = self.corrupt_key.take() {
+            return Some(Err(crate::Error::Decode(corrupt_key)));
+        }
+
         let mut current_range_start = self.current_range_start.clone();
 
         loop {
@@ -115,33 +163,46 @@ impl Reader {
                         .map(|(k, v)| k.len() as u64 + v.len() as u64)
                         .sum::<u64>();
 
-                    let (last_key, _) = chunk.last().unwrap();
+                    let (last_key, _) = chunk.last().expect("chunk should not be empty");
                     current_range_start = Excluded(last_key.clone());
 
-                    self.buffer.extend(
-                        chunk
-                            .into_iter()
-                            .map(|(k, v)| VisitedCell::parse(k, &v))
-                            .collect::<Vec<_>>(),
-                    );
+                    for (k, v) in chunk {
+                        match VisitedCell::parse(k.clone(), &v) {
+                            Ok(cell) => self.buffer.push_back(cell),
+                            Err(_) => {
+                                // NOTE: Cells after the corrupt cell are read again in the next chunk
+                                current_range_start = Excluded(k.clone());
+                                self.corrupt_key = Some(k);
+                                break;
+                            }
+                        }
+                    }
+
+                    self.current_range_start = current_range_start.clone();
 
                     if let Some(cell) = self.buffer.front().cloned() {
-                        self.current_range_start = current_range_start;
                         return Some(Ok(cell));
                     }
+
+                    if let Some(corrupt_key) = self.corrupt_key.take() {
+                        return Some(Err(crate::Error::Decode(corrupt_key)));
+                    }
                 }
-                Err(e) => return Some(Err(fjall::Error::Storage(e))),
+                Err(e) => return Some(Err(crate::Error::Tree(e))),
             }
         }
     }
 }
 
 impl Iterator for &mut Reader {
-    type Item = fjall::Result<VisitedCell>;
+    type Item = crate::Result<VisitedCell>;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.peek()? {
-            Ok(_) => Some(Ok(self.buffer.pop_front().unwrap())),
+            Ok(_) => Some(Ok(self
+                .buffer
+                .pop_front()
+                .expect("buffer should not be empty"))),
             Err(e) => Some(Err(e)),
         }
     }
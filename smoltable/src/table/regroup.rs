@@ -0,0 +1,396 @@
+use super::{reader::Reader as TableReader, writer::CellMutation, Smoltable};
+use crate::VisitedCell;
+use fjall::PartitionHandle;
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Bound,
+    sync::Arc,
+};
+
+/// Amount of copied cells per write batch
+const REGROUP_BATCH_SIZE: usize = 10_000;
+
+/// Locality group column families are moved into
+#[derive(Clone, Debug, Deserialize)]
+pub enum LocalityGroupTarget {
+    /// The default locality group
+    #[serde(rename = "default")]
+    Default,
+
+    /// A new, dedicated locality group
+    #[serde(rename = "new")]
+    New,
+
+    /// An existing locality group
+    #[serde(rename = "existing")]
+    Existing(String),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegroupColumnFamiliesInput {
+    pub column_families: Vec<String>,
+    pub target: LocalityGroupTarget,
+}
+
+/// Column families whose cell mutations are mirrored into a second partition,
+/// while they are moved between locality groups
+pub(crate) struct RegroupMirror {
+    pub families: Vec<Arc<str>>,
+    pub partition: PartitionHandle,
+
+    /// Inserts are only mirrored for keys after this key, because the partition
+    /// has already been cleaned up before it; deletes are always mirrored
+    pub inserts_after: Option<Arc<[u8]>>,
+}
+
+impl RegroupMirror {
+    /// Returns `true` if the mutation needs to be applied to the mirror partition as well
+    pub fn is_mirrored(&self, mutation: &CellMutation) -> bool {
+        if !self
+            .families
+            .iter()
+            .any(|family| **family == *mutation.family)
+        {
+            return false;
+        }
+
+        match (&mutation.value, &self.inserts_after) {
+            (Some(_), Some(inserts_after)) => mutation.key > *inserts_after,
+            _ => true,
+        }
+    }
+}
+
+impl Smoltable {
+    /// Moves column families into another locality group.
+    ///
+    /// While the column families are moved, all writes to them are mirrored into
+    /// the target locality group, and cells are copied in batches from the latest state.
+    /// Then the manifest mapping is switched atomically, and the old cells are deleted
+    /// in batches, while writes are mirrored into the old locality group.
+    /// Writes are only blocked while a batch is copied or deleted.
+    ///
+    /// Until the old cells are deleted, reads may visit both copies, which are
+    /// identical and deduplicated.
+    ///
+    /// Returns the amount of moved cells.
+    pub fn regroup_column_families(
+        &self,
+        input: &RegroupColumnFamiliesInput,
+    ) -> crate::Result<u64> {
+        log::debug!(
+            "Regrouping column families {:?} into {:?} for table {:?}",
+            input.column_families,
+            input.target,
+            self.name
+        );
+
+        let _regroup_lock = self.regroup_lock.lock().expect("lock is poisoned");
+
+        let result = self.move_column_families(input);
+
+        // NOTE: Also stop mirroring if the regroup failed
+        self.write_gate.write().expect("lock is poisoned").clear();
+
+        result
+    }
+
+    fn move_column_families(&self, input: &RegroupColumnFamiliesInput) -> crate::Result<u64> {
+        let mut names = input
+            .column_families
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+
+        names.sort_unstable();
+        names.dedup();
+
+        if names.is_empty() {
+            return Ok(0);
+        }
+
+        {
+            let column_families = self.column_families.read().expect("lock is poisoned");
+
+            if let Some(name) = names
+                .iter()
+                .find(|&&name| !column_families.contains_key(name))
+            {
+                return Err(crate::Error::ColumnFamilyNotFound((*name).to_owned()));
+            }
+        }
+
+        let (target_id, target) = match &input.target {
+            LocalityGroupTarget::Default => (None, self.tree.clone()),
+            LocalityGroupTarget::New => {
+                let id = nanoid::nanoid!();
+                let tree = self.open_locality_group_partition(&id, &Default::default())?;
+                (Some(id), tree)
+            }
+            LocalityGroupTarget::Existing(id) => {
+                let tree = self
+                    .locality_groups
+                    .read()
+                    .expect("lock is poisoned")
+                    .iter()
+                    .find(|lg| &*lg.id == id)
+                    .map(|lg| lg.tree.clone())
+                    .ok_or_else(|| crate::Error::LocalityGroupNotFound(id.clone()))?;
+
+                (Some(id.clone()), tree)
+            }
+        };
+
+        // Group the moved column families by the partition they are currently stored in
+        let mut sources: HashMap<Arc<str>, (PartitionHandle, Vec<Arc<str>>)> = HashMap::new();
+
+        for name in names {
+            let source = self.get_partition_for_column_family(name)?;
+
+            if source.name == target.name {
+                continue;
+            }
+
+            sources
+                .entry(source.name.clone())
+                .or_insert_with(|| (source.clone(), vec![]))
+                .1
+                .push(name.into());
+        }
+
+        let moved_names = sources
+            .values()
+            .flat_map(|(_, names)| names.iter().cloned())
+            .collect::<Vec<_>>();
+
+        // 1. Mirror writes into the target, and copy the cells
+        self.write_gate
+            .write()
+            .expect("lock is poisoned")
+            .push(RegroupMirror {
+                families: moved_names.clone(),
+                partition: target.clone(),
+                inserts_after: None,
+            });
+
+        let mut moved_cells_count = 0;
+
+        for (source, names) in sources.values() {
+            moved_cells_count += self.copy_cells(source, &target, names)?;
+        }
+
+        // 2. Switch the manifest mapping atomically
+        let mut emptied_partitions = vec![];
+
+        {
+            let mut mirrors = self.write_gate.write().expect("lock is poisoned");
+
+            let mut batch = self.keyspace.batch();
+
+            for lg in self
+                .locality_groups
+                .read()
+                .expect("lock is poisoned")
+                .iter()
+            {
+                let is_target = target_id.as_deref() == Some(&*lg.id);
+
+                let mut names = lg
+                    .column_families
+                    .iter()
+                    .filter(|&name| !moved_names.contains(name))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if is_target {
+                    names.extend(moved_names.iter().cloned());
+                }
+
+                if names.len() == lg.column_families.len() && !is_target {
+                    continue;
+                }
+
+                let key = format!("lg#{}", lg.id);
+
+                if names.is_empty() {
+                    batch.remove(&self.manifest, key);
+                    batch.remove(&self.manifest, format!("lgopt#{}", lg.id));
+                    emptied_partitions.push(lg.tree.clone());
+                } else {
+                    let str = serde_json::to_string(&names).expect("should serialize");
+                    batch.insert(&self.manifest, key, str);
+                }
+            }
+
+            if let (LocalityGroupTarget::New, Some(id)) = (&input.target, &target_id) {
+                let str = serde_json::to_string(&moved_names).expect("should serialize");
+                batch.insert(&self.manifest, format!("lg#{id}"), str);
+            }
+
+            batch.commit()?;
+            self.keyspace.persist(fjall::PersistMode::SyncAll)?;
+
+            self.load_locality_groups()?;
+
+            // NOTE: Writes now go into the target, but are mirrored into
+            // the old locality groups until their cells are deleted
+            *mirrors = sources
+                .values()
+                .filter(|(source, _)| {
+                    !emptied_partitions
+                        .iter()
+                        .any(|partition: &PartitionHandle| partition.name == source.name)
+                })
+                .map(|(source, names)| RegroupMirror {
+                    families: names.clone(),
+                    partition: source.clone(),
+                    inserts_after: None,
+                })
+                .collect();
+        }
+
+        // 3. Delete the old cells
+        for (source, names) in sources.values() {
+            if emptied_partitions
+                .iter()
+                .any(|partition| partition.name == source.name)
+            {
+                continue;
+            }
+
+            self.delete_cells(source, names)?;
+        }
+
+        self.keyspace.persist(fjall::PersistMode::SyncAll)?;
+
+        for partition in emptied_partitions {
+            self.keyspace.delete_partition(partition)?;
+        }
+
+        Ok(moved_cells_count)
+    }
+
+    /// Reads the next batch of cells of a partition from its latest state
+    ///
+    /// Returns the cells and whether the partition has been read entirely.
+    /// Needs to be called while holding the write gate.
+    fn read_batch(
+        &self,
+        partition: &PartitionHandle,
+        cursor: &Bound<Arc<[u8]>>,
+    ) -> crate::Result<(Vec<VisitedCell>, bool)> {
+        let mut reader =
+            TableReader::new(self.keyspace.instant(), partition.clone(), cursor.clone());
+
+        let mut cells = Vec::with_capacity(REGROUP_BATCH_SIZE);
+
+        for cell in &mut reader {
+            cells.push(cell?);
+
+            if cells.len() >= REGROUP_BATCH_SIZE {
+                return Ok((cells, false));
+            }
+        }
+
+        Ok((cells, true))
+    }
+
+    /// Copies all cells of the given column families into the target partition
+    ///
+    /// Writes are blocked while a batch is copied, and mirrored into the target in between,
+    /// so the target ends up with exactly the cells of the source.
+    fn copy_cells(
+        &self,
+        source: &PartitionHandle,
+        target: &PartitionHandle,
+        names: &[Arc<str>],
+    ) -> crate::Result<u64> {
+        let is_moved =
+            |cell: &VisitedCell| names.iter().any(|name| **name == *cell.column_key.family);
+
+        let mut cursor = Bound::Unbounded;
+        let mut copied_cells_count = 0;
+
+        loop {
+            let _mirrors = self.write_gate.write().expect("lock is poisoned");
+
+            let (cells, is_exhausted) = self.read_batch(source, &cursor)?;
+            let last_key = cells.last().map(|cell| cell.raw_key.clone());
+
+            let mut batch = self.keyspace.batch();
+            let mut copied_keys = HashSet::new();
+
+            for cell in cells.into_iter().filter(is_moved) {
+                batch.insert(target, &*cell.raw_key, cell.value.to_bytes());
+                copied_keys.insert(cell.raw_key);
+                copied_cells_count += 1;
+            }
+
+            // NOTE: Remove cells of the column families that only exist in the target,
+            // e.g. left over by an interrupted regroup, so they cannot reappear
+            let mut reader = TableReader::new(self.keyspace.instant(), target.clone(), cursor);
+
+            for cell in &mut reader {
+                let cell = cell?;
+
+                if !is_exhausted && last_key.as_ref().is_some_and(|key| cell.raw_key > *key) {
+                    break;
+                }
+
+                if is_moved(&cell) && !copied_keys.contains(&cell.raw_key) {
+                    batch.remove(target, &*cell.raw_key);
+                }
+            }
+
+            batch.commit()?;
+
+            match last_key {
+                Some(key) if !is_exhausted => cursor = Bound::Excluded(key),
+                _ => return Ok(copied_cells_count),
+            }
+        }
+    }
+
+    /// Deletes all cells of the given column families from the source partition
+    ///
+    /// Writes are blocked while a batch is deleted. In between, inserts are only
+    /// mirrored into the part of the source that has not been deleted yet.
+    fn delete_cells(&self, source: &PartitionHandle, names: &[Arc<str>]) -> crate::Result<()> {
+        let mut cursor = Bound::Unbounded;
+
+        loop {
+            let mut mirrors = self.write_gate.write().expect("lock is poisoned");
+
+            let (cells, is_exhausted) = self.read_batch(source, &cursor)?;
+            let last_key = cells.last().map(|cell| cell.raw_key.clone());
+
+            let mut batch = self.keyspace.batch();
+
+            for cell in cells {
+                if names.iter().any(|name| **name == *cell.column_key.family) {
+                    batch.remove(source, &*cell.raw_key);
+                }
+            }
+
+            batch.commit()?;
+
+            match last_key {
+                Some(key) if !is_exhausted => {
+                    if let Some(mirror) = mirrors
+                        .iter_mut()
+                        .find(|mirror| mirror.partition.name == source.name)
+                    {
+                        mirror.inserts_after = Some(key.clone());
+                    }
+
+                    cursor = Bound::Excluded(key);
+                }
+                _ => {
+                    mirrors.retain(|mirror| mirror.partition.name != source.name);
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
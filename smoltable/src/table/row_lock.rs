@@ -0,0 +1,58 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    sync::{Mutex, MutexGuard},
+};
+
+/// Striped locks used to serialize writes and read-modify-write operations on a row
+///
+/// Rows are hashed into a fixed amount of stripes, so unrelated rows
+/// may share a lock, but the same row always maps to the same lock.
+pub struct RowLocks(Vec<Mutex<()>>);
+
+impl RowLocks {
+    pub fn new(stripe_count: usize) -> Self {
+        Self((0..stripe_count).map(|_| Mutex::default()).collect())
+    }
+
+    fn stripe_idx(&self, row_key: &str) -> usize {
+        let mut hasher = DefaultHasher::new();
+        row_key.hash(&mut hasher);
+
+        (hasher.finish() % self.0.len() as u64) as usize
+    }
+
+    fn lock_stripe(&self, idx: usize) -> MutexGuard<'_, ()> {
+        self.0
+            .get(idx)
+            .expect("stripe should exist")
+            .lock()
+            .expect("lock is poisoned")
+    }
+
+    /// Locks the given row until the guard is dropped
+    pub fn lock(&self, row_key: &str) -> MutexGuard<'_, ()> {
+        self.lock_stripe(self.stripe_idx(row_key))
+    }
+
+    /// Locks all given rows until the guards are dropped
+    ///
+    /// Stripes are always locked in ascending order, so concurrent callers cannot deadlock.
+    pub fn lock_many<'a>(
+        &self,
+        row_keys: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<MutexGuard<'_, ()>> {
+        let mut indexes = row_keys
+            .into_iter()
+            .map(|row_key| self.stripe_idx(row_key))
+            .collect::<Vec<_>>();
+
+        indexes.sort_unstable();
+        indexes.dedup();
+
+        indexes
+            .into_iter()
+            .map(|idx| self.lock_stripe(idx))
+            .collect()
+    }
+}
@@ -1,7 +1,8 @@
-use super::reader::Reader as TableReader;
+use super::{merge_reader::MergeReader, reader::Reader as TableReader};
 use crate::query::row::Input;
-use crate::{ColumnFilter, Smoltable, VisitedCell};
+use crate::{ColumnFilter, ColumnKey, ColumnRange, Smoltable, VisitedCell};
 use fjall::PartitionHandle;
+use std::{ops::Bound, sync::Arc};
 
 pub fn get_affected_locality_groups(
     table: &Smoltable,
@@ -11,11 +12,20 @@ pub fn get_affected_locality_groups(
 
     if let Some(cf) = column_filter {
         match cf {
-            ColumnFilter::Key(key) | ColumnFilter::Prefix(key) => {
+            ColumnFilter::Key(ColumnKey {
+                family: column_family_name,
+                ..
+            })
+            | ColumnFilter::Prefix(ColumnKey {
+                family: column_family_name,
+                ..
+            })
+            | ColumnFilter::Range(ColumnRange {
+                family: column_family_name,
+                ..
+            }) => {
                 let lock = table.locality_groups.read().expect("lock is poisoned");
 
-                let column_family_name = &key.family;
-
                 if table
                     .column_families_in_default_locality_group()?
                     .contains(column_family_name)
@@ -69,134 +79,147 @@ pub fn get_affected_locality_groups(
 }
 
 pub struct SingleRowReader {
-    inner: Option<TableReader>,
+    inner: MergeReader,
     input: Input,
-    instant: fjall::Instant,
-    locality_groups: Vec<PartitionHandle>,
-    cells_scanned_count: u64,
-    bytes_scanned_count: u64,
+    locality_group_count: usize,
 }
 
 impl SingleRowReader {
     pub fn new(table: &Smoltable, instant: fjall::Instant, input: Input) -> crate::Result<Self> {
         let column_filter = input.column.as_ref().and_then(|x| x.filter.clone());
         let locality_groups = get_affected_locality_groups(table, &column_filter)?;
+        let locality_group_count = locality_groups.len();
+
+        // NOTE: Locality groups are merged, so columns are visited in key order,
+        // which is required for resuming from a column cursor
+        let readers = locality_groups
+            .into_iter()
+            .map(|locality_group| Self::create_reader(instant, locality_group, &input))
+            .collect::<fjall::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect();
 
         Ok(Self {
-            inner: None,
+            inner: MergeReader::new(readers),
             input,
-            instant,
-            locality_groups,
-            bytes_scanned_count: 0,
-            cells_scanned_count: 0,
+            locality_group_count,
         })
     }
 
     pub fn locality_group_count(&self) -> usize {
-        self.locality_groups.len()
+        self.locality_group_count
     }
 
     pub fn cells_scanned_count(&self) -> u64 {
-        self.cells_scanned_count
-            + self
-                .inner
-                .as_ref()
-                .map(|x| x.cells_scanned_count)
-                .unwrap_or_default()
+        self.inner.cells_scanned_count()
     }
 
     pub fn bytes_scanned_count(&self) -> u64 {
-        self.bytes_scanned_count
-            + self
-                .inner
-                .as_ref()
-                .map(|x| x.bytes_scanned_count)
-                .unwrap_or_default()
+        self.inner.bytes_scanned_count()
     }
 
-    fn take_next_locality_group(&mut self) -> fjall::Result<bool> {
-        let column_filter = self.input.column.as_ref().and_then(|x| x.filter.as_ref());
+    fn create_reader(
+        instant: fjall::Instant,
+        locality_group: PartitionHandle,
+        input: &Input,
+    ) -> fjall::Result<Option<TableReader>> {
+        let row_key = &input.row.key;
+        let column_filter = input.column.as_ref().and_then(|x| x.filter.as_ref());
+        let column_cursor = input.column.as_ref().and_then(|x| x.start_after.as_ref());
+
+        if let Some(column_cursor) = column_cursor {
+            // NOTE: Seek past all versions of the cursor column
+            let cursor_key: Arc<[u8]> = VisitedCell::format_key(row_key, column_cursor, 0).into();
+
+            let start = match column_filter {
+                Some(ColumnFilter::Range(range)) => {
+                    let start: Arc<[u8]> = range.build_start_key(row_key).into();
+
+                    if start > cursor_key {
+                        Bound::Included(start)
+                    } else {
+                        Bound::Excluded(cursor_key)
+                    }
+                }
+                _ => Bound::Excluded(cursor_key),
+            };
 
-        let locality_group = self.locality_groups.remove(0);
+            return Ok(Some(TableReader::new(instant, locality_group, start)));
+        }
 
         // TODO: optimize Multi Column filter to only scan columns, not entire column family
-        let prefix = match column_filter {
-            Some(ColumnFilter::Key(filter)) => filter.build_key(&self.input.row.key),
-            _ => format!("{}:", self.input.row.key),
-        };
+        match column_filter {
+            Some(ColumnFilter::Key(filter)) => {
+                TableReader::from_prefix(instant, locality_group, &filter.build_key(row_key))
+            }
+            Some(ColumnFilter::Range(range)) => {
+                // NOTE: Seek directly to the start qualifier
+                let start = range.build_start_key(row_key);
+
+                Ok(Some(TableReader::new(
+                    instant,
+                    locality_group,
+                    Bound::Included(start.into()),
+                )))
+            }
+            _ => {
+                TableReader::from_prefix(instant, locality_group, &crate::key::row_prefix(row_key))
+            }
+        }
+    }
 
-        let Some(reader) = TableReader::from_prefix(self.instant, locality_group, &prefix)? else {
-            return Ok(false);
-        };
-        self.inner = Some(reader);
+    /// Returns `true` if no more cells of the row can follow
+    fn is_past_row(&self, cell: &VisitedCell) -> bool {
+        if cell.row_key != self.input.row.key {
+            return true;
+        }
+
+        let column_filter = self.input.column.as_ref().and_then(|x| x.filter.as_ref());
 
-        Ok(true)
+        match column_filter {
+            // NOTE: Columns are visited in order, so we can stop once we have left the range
+            Some(ColumnFilter::Range(range)) => {
+                cell.column_key.family != range.family
+                    || range.is_past_end(cell.column_key.qualifier.as_deref().unwrap_or_default())
+            }
+            _ => false,
+        }
     }
 }
 
 impl Iterator for &mut SingleRowReader {
-    type Item = fjall::Result<VisitedCell>;
+    type Item = crate::Result<VisitedCell>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.inner.is_none() {
-            // Initialize reader
-            match self.take_next_locality_group() {
-                Ok(has_range) => {
-                    if !has_range {
-                        return None;
-                    }
-                }
+        loop {
+            let cell = match (&mut self.inner).next()? {
+                Ok(cell) => cell,
                 Err(e) => return Some(Err(e)),
             };
-        }
-
-        loop {
-            let mut reader = self.inner.as_mut().unwrap();
-            let cell = reader.next();
-
-            match cell {
-                Some(cell) => {
-                    let cell = match cell {
-                        Ok(cell) => cell,
-                        Err(e) => return Some(Err(e)),
-                    };
-
-                    if cell.row_key != self.input.row.key {
-                        return None;
-                    }
 
-                    let column_filter = self.input.column.as_ref().and_then(|x| x.filter.as_ref());
+            if self.is_past_row(&cell) {
+                return None;
+            }
 
-                    if let Some(filter) = column_filter {
-                        if !cell.satisfies_column_filter(filter) {
-                            continue;
-                        }
-                    }
+            let column_filter = self.input.column.as_ref().and_then(|x| x.filter.as_ref());
 
-                    return Some(Ok(cell));
+            if let Some(filter) = column_filter {
+                if !cell.satisfies_column_filter(filter) {
+                    continue;
                 }
-                None => {
-                    self.bytes_scanned_count += reader.bytes_scanned_count;
-                    self.cells_scanned_count += reader.cells_scanned_count;
-                    self.inner = None;
-
-                    // Iterator is empty
-                    if !self.locality_groups.is_empty() {
-                        // Load next one
-                        match self.take_next_locality_group() {
-                            Ok(has_range) => {
-                                if !has_range {
-                                    return None;
-                                }
-                            }
-                            Err(e) => return Some(Err(e)),
-                        };
-                    } else {
-                        // It's over
-                        return None;
-                    }
+            }
+
+            let time_range = self.input.cell.as_ref().and_then(|x| x.time.as_ref());
+
+            if let Some(time_range) = time_range {
+                if let Some(start) = cell.seek_key_for_time_range(time_range) {
+                    self.inner.seek(start);
+                    continue;
                 }
             }
+
+            return Some(Ok(cell));
         }
     }
 }
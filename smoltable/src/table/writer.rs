@@ -1,11 +1,65 @@
-use super::Smoltable;
-use crate::{CellValue, ColumnKey, VisitedCell};
-use fjall::Batch;
+use super::{row_reader::SingleRowReader, Smoltable};
+use crate::{
+    query::{
+        row::{
+            CellOptions as QueryRowCellOptions, ColumnOptions as QueryRowColumnOptions,
+            Input as QueryRowInput, RowOptions as QueryRowInputRowOptions,
+        },
+        scan::TimeRange,
+    },
+    CellValue, ColumnFilter, ColumnKey, VisitedCell,
+};
 use serde::Deserialize;
+use std::{collections::BTreeMap, sync::Arc};
 
 pub struct Writer {
     table: Smoltable,
-    batch: Batch,
+
+    /// Row mutations in batch order
+    ///
+    /// Deletes are only resolved into cell mutations once the rows are locked.
+    row_mutations: Vec<RowMutation>,
+
+    /// Rows that are written, locked while the batch is committed
+    row_keys: Vec<String>,
+
+    /// Set if the caller already holds the row locks of all written rows
+    is_row_locked: bool,
+}
+
+/// An insert or delete of a single cell
+///
+/// The locality group the cell is stored in is only resolved once the
+/// mutation is committed, so it cannot change in the meantime.
+pub(crate) struct CellMutation {
+    pub family: String,
+    pub key: Arc<[u8]>,
+
+    /// Encoded cell value, or `None` to delete the cell
+    pub value: Option<Vec<u8>>,
+}
+
+/// A write or delete inside a row, in the order it was added to the batch
+enum RowMutation {
+    Cell {
+        row_key: String,
+        column_key: ColumnKey,
+        timestamp: u128,
+        value: Vec<u8>,
+    },
+    Delete {
+        row_key: String,
+        delete: DeleteWriteItem,
+    },
+}
+
+/// The latest mutation of a cell inside the batch
+struct PendingCell {
+    column_key: ColumnKey,
+    timestamp: u128,
+
+    /// Encoded cell value, or `None` to delete the cell
+    value: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -19,10 +73,51 @@ pub struct ColumnWriteItem {
     pub value: CellValue,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub enum DeleteWriteItem {
+    /// Deletes all versions of a column, optionally only inside a timestamp range
+    #[serde(rename = "column")]
+    Column {
+        column_key: ColumnKey,
+        time: Option<TimeRange>,
+    },
+
+    /// Deletes all columns of a column family
+    #[serde(rename = "family")]
+    Family(String),
+
+    /// Deletes all cells of the row
+    #[serde(rename = "row")]
+    Row,
+}
+
+impl DeleteWriteItem {
+    /// Returns `true` if the cell is targeted by the delete
+    fn matches(&self, cell_column_key: &ColumnKey, timestamp: u128) -> bool {
+        match self {
+            DeleteWriteItem::Column { column_key, time } => {
+                // NOTE: The column filter matches the entire family if the qualifier
+                // is empty, so we need to check the qualifier explicitly
+                cell_column_key.family == column_key.family
+                    && cell_column_key.qualifier.as_deref().unwrap_or_default()
+                        == column_key.qualifier.as_deref().unwrap_or_default()
+                    && time.as_ref().map_or(true, |time| time.contains(timestamp))
+            }
+            DeleteWriteItem::Family(family) => cell_column_key.family == *family,
+            DeleteWriteItem::Row => true,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct RowWriteItem {
     pub row_key: String,
     pub cells: Vec<ColumnWriteItem>,
+
+    /// Deletes are applied before the cells of the same item are written,
+    /// and also affect cells written by earlier items of the batch
+    #[serde(default)]
+    pub deletes: Vec<DeleteWriteItem>,
 }
 
 pub fn timestamp_nano() -> u128 {
@@ -34,16 +129,18 @@ pub fn timestamp_nano() -> u128 {
 
 impl Writer {
     pub fn new(target_table: Smoltable) -> Self {
-        let batch = target_table.batch();
-
         Self {
             table: target_table,
-            batch,
+            row_mutations: vec![],
+            row_keys: vec![],
+            is_row_locked: false,
         }
     }
 
     /// Convenience function for opening and committing a write batch
     pub fn write_batch(table: Smoltable, items: &[RowWriteItem]) -> crate::Result<()> {
+        table.check_column_families(items)?;
+
         let mut writer = Self::new(table);
         for item in items {
             writer.write(item)?;
@@ -52,28 +149,191 @@ impl Writer {
         Ok(())
     }
 
+    /// Like [`Writer::write_batch`], for callers that already hold the row locks of all written rows
+    pub(crate) fn write_batch_locked(
+        table: Smoltable,
+        items: &[RowWriteItem],
+    ) -> crate::Result<()> {
+        table.check_column_families(items)?;
+
+        let mut writer = Self::new(table);
+        writer.is_row_locked = true;
+
+        for item in items {
+            writer.write(item)?;
+        }
+        writer.finalize()?;
+        Ok(())
+    }
+
     /// Appends to the write batch
+    ///
+    /// Fails if the row contains undefined column families and the schema is enforced,
+    /// if a cell value does not have the value type defined for its column,
+    /// or if a cell value is an invalid array.
     pub fn write(&mut self, item: &RowWriteItem) -> crate::Result<()> {
+        self.table
+            .check_column_families(std::slice::from_ref(item))?;
+        self.table.check_value_types(item)?;
+
+        if let Some(cell) = item.cells.iter().find(|cell| !cell.value.is_valid()) {
+            return Err(crate::Error::InvalidValueType(cell.column_key.clone()));
+        }
+
+        self.row_keys.push(item.row_key.clone());
+
+        for delete in &item.deletes {
+            self.row_mutations.push(RowMutation::Delete {
+                row_key: item.row_key.clone(),
+                delete: delete.clone(),
+            });
+        }
+
         for cell in &item.cells {
-            let key = VisitedCell::format_key(
-                &item.row_key,
-                &cell.column_key,
-                cell.timestamp.unwrap_or_else(timestamp_nano),
-            );
-
-            let partition = self
-                .table
-                .get_partition_for_column_family(&cell.column_key.family)?;
-
-            let encoded_value = cell.value.to_bytes();
-            self.batch.insert(&partition, key, encoded_value);
+            self.row_mutations.push(RowMutation::Cell {
+                row_key: item.row_key.clone(),
+                column_key: cell.column_key.clone(),
+                timestamp: cell.timestamp.unwrap_or_else(timestamp_nano),
+                value: cell.value.to_bytes(),
+            });
         }
 
         Ok(())
     }
 
+    /// Resolves the row mutations into cell mutations, applying them in batch order
+    ///
+    /// Needs to be called while the written rows are locked, so no cell
+    /// targeted by a delete can be written in the meantime.
+    fn resolve_mutations(&self) -> crate::Result<Vec<CellMutation>> {
+        // NOTE: Keyed by cell key, so a later mutation of a cell replaces an earlier one
+        let mut cells: BTreeMap<Arc<[u8]>, PendingCell> = BTreeMap::new();
+
+        for row_mutation in &self.row_mutations {
+            match row_mutation {
+                RowMutation::Cell {
+                    row_key,
+                    column_key,
+                    timestamp,
+                    value,
+                } => {
+                    let key = VisitedCell::format_key(row_key, column_key, *timestamp);
+
+                    cells.insert(
+                        key.into(),
+                        PendingCell {
+                            column_key: column_key.clone(),
+                            timestamp: *timestamp,
+                            value: Some(value.clone()),
+                        },
+                    );
+                }
+                RowMutation::Delete { row_key, delete } => {
+                    // NOTE: Delete cells written by earlier items of the batch
+                    let row_prefix: Arc<[u8]> = crate::key::row_prefix(row_key).into();
+
+                    for (_, cell) in cells
+                        .range_mut(row_prefix.clone()..)
+                        .take_while(|(key, _)| key.starts_with(&row_prefix))
+                    {
+                        if delete.matches(&cell.column_key, cell.timestamp) {
+                            cell.value = None;
+                        }
+                    }
+
+                    for cell in self.read_deleted_cells(row_key, delete)? {
+                        cells.insert(
+                            cell.raw_key,
+                            PendingCell {
+                                column_key: cell.column_key,
+                                timestamp: cell.timestamp,
+                                value: None,
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        Ok(cells
+            .into_iter()
+            .map(|(key, cell)| CellMutation {
+                family: cell.column_key.family,
+                key,
+                value: cell.value,
+            })
+            .collect())
+    }
+
+    /// Reads the stored cells targeted by the delete
+    fn read_deleted_cells(
+        &self,
+        row_key: &str,
+        item: &DeleteWriteItem,
+    ) -> crate::Result<Vec<VisitedCell>> {
+        let (column_filter, time_range) = match item {
+            DeleteWriteItem::Column { column_key, time } => {
+                (Some(ColumnFilter::Key(column_key.clone())), time.clone())
+            }
+            DeleteWriteItem::Family(family) => (
+                Some(ColumnFilter::Key(ColumnKey {
+                    family: family.clone(),
+                    qualifier: None,
+                })),
+                None,
+            ),
+            DeleteWriteItem::Row => (None, None),
+        };
+
+        let mut reader = SingleRowReader::new(
+            &self.table,
+            self.table.keyspace.instant(),
+            QueryRowInput {
+                row: QueryRowInputRowOptions {
+                    key: row_key.into(),
+                    cell_limit: None,
+                },
+                column: column_filter.map(|filter| QueryRowColumnOptions {
+                    cell_limit: None,
+                    limit: None,
+                    start_after: None,
+                    filter: Some(filter),
+                }),
+                cell: time_range.map(|time| QueryRowCellOptions { time: Some(time) }),
+                filter: None,
+            },
+        )?;
+
+        let mut cells = vec![];
+
+        for cell in &mut reader {
+            let cell = cell?;
+
+            if item.matches(&cell.column_key, cell.timestamp) {
+                cells.push(cell);
+            }
+        }
+
+        Ok(cells)
+    }
+
+    /// Commits the write batch
+    ///
+    /// The written rows are locked while the deleted cells are looked up and the batch
+    /// is committed, so the batch is serialized with conditional mutations and
+    /// read-modify-writes on the same rows.
     pub fn finalize(self) -> crate::Result<()> {
-        self.batch.commit()?;
+        {
+            let _row_locks = (!self.is_row_locked).then(|| {
+                self.table
+                    .row_locks
+                    .lock_many(self.row_keys.iter().map(String::as_str))
+            });
+
+            let mutations = self.resolve_mutations()?;
+            self.table.commit_mutations(mutations)?;
+        }
+
         self.table.keyspace.persist(fjall::PersistMode::SyncAll)?;
         Ok(())
     }
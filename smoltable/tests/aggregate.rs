@@ -0,0 +1,195 @@
+use smoltable::{
+    query::{
+        aggregate::{
+            CellOptions as AggregateCellOptions, GroupBy, Input as AggregateInput,
+            RowOptions as AggregateRowOptions,
+        },
+        filter::RowFilter,
+        scan::{ScanMode, TimeRange},
+    },
+    CellValue, ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions,
+    Smoltable, TableWriter,
+};
+use test_log::test;
+
+#[test]
+pub fn aggregate_scan() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for (row_key, base) in [("sensor#a#1", 0), ("sensor#a#2", 10), ("sensor#b#1", 100)] {
+        for ts in 0..5 {
+            writer.write(&smoltable::row!(
+                row_key,
+                vec![
+                    smoltable::cell!("value:temp", Some(ts), CellValue::F64((base + ts) as f64)),
+                    smoltable::cell!("value:unit", Some(ts), CellValue::String("C".into())),
+                ]
+            ))?;
+        }
+    }
+
+    writer.write(&smoltable::row!(
+        "other",
+        vec![smoltable::cell!(
+            "value:temp",
+            Some(0),
+            CellValue::I32(1000)
+        )]
+    ))?;
+
+    writer.finalize()?;
+
+    let result = table.aggregate(AggregateInput {
+        row: AggregateRowOptions {
+            scan: ScanMode::Prefix("sensor#".into()),
+            group_by: None,
+            group_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+
+    // NOTE: Only the latest version of every column is aggregated by default
+    assert_eq!(3, result.row_count);
+    assert_eq!(3, result.cell_count);
+    assert_eq!(1, result.groups.len());
+    assert!(!result.is_group_limit_reached);
+
+    let group = result.groups.first().unwrap();
+    assert_eq!("", group.key);
+    assert_eq!(vec!["value:temp"], group.columns.keys().collect::<Vec<_>>());
+
+    let temp = &group.columns["value:temp"];
+    assert_eq!(3, temp.count);
+    assert_eq!(122.0, temp.sum);
+    assert_eq!(Some(4.0), temp.min);
+    assert_eq!(Some(104.0), temp.max);
+    assert_eq!(Some(122.0 / 3.0), temp.avg);
+
+    let result = table.aggregate(AggregateInput {
+        row: AggregateRowOptions {
+            scan: ScanMode::Prefix("sensor#".into()),
+            group_by: None,
+            group_limit: None,
+        },
+        column: None,
+        cell: Some(AggregateCellOptions {
+            time: None,
+            all_versions: true,
+        }),
+        ..Default::default()
+    })?;
+
+    assert_eq!(3, result.row_count);
+    assert_eq!(15, result.cell_count);
+
+    let temp = &result.groups[0].columns["value:temp"];
+    assert_eq!(15, temp.count);
+    assert_eq!(580.0, temp.sum);
+    assert_eq!(Some(0.0), temp.min);
+    assert_eq!(Some(104.0), temp.max);
+    assert_eq!(Some(580.0 / 15.0), temp.avg);
+
+    let result = table.aggregate(AggregateInput {
+        row: AggregateRowOptions {
+            scan: ScanMode::Prefix("sensor#".into()),
+            group_by: Some(GroupBy::Delimiter("#".into())),
+            group_limit: None,
+        },
+        column: None,
+        cell: Some(AggregateCellOptions {
+            time: Some(TimeRange {
+                start: Some(1),
+                end: Some(4),
+            }),
+            all_versions: true,
+        }),
+        ..Default::default()
+    })?;
+
+    assert_eq!(
+        vec![("sensor#a#", 2), ("sensor#b#", 1)],
+        result
+            .groups
+            .iter()
+            .map(|group| (group.key.as_str(), group.row_count))
+            .collect::<Vec<_>>()
+    );
+
+    let temp = &result.groups[0].columns["value:temp"];
+    assert_eq!(6, temp.count);
+    assert_eq!(Some(1.0), temp.min);
+    assert_eq!(Some(13.0), temp.max);
+    assert_eq!(Some(1), temp.first.as_ref().map(|cell| cell.timestamp));
+    assert_eq!(Some(3), temp.last.as_ref().map(|cell| cell.timestamp));
+
+    let result = table.aggregate(AggregateInput {
+        row: AggregateRowOptions {
+            scan: ScanMode::Prefix(String::new()),
+            group_by: Some(GroupBy::PrefixLength(1)),
+            group_limit: None,
+        },
+        column: None,
+        cell: Some(AggregateCellOptions {
+            time: None,
+            all_versions: true,
+        }),
+        filter: Some(RowFilter::Latest(1)),
+    })?;
+
+    assert_eq!(
+        vec![("o", 1000.0), ("s", 122.0)],
+        result
+            .groups
+            .iter()
+            .map(|group| (group.key.as_str(), group.columns["value:temp"].sum))
+            .collect::<Vec<_>>()
+    );
+
+    // NOTE: The latest version inside of the time range is aggregated
+    let result = table.aggregate(AggregateInput {
+        row: AggregateRowOptions {
+            scan: ScanMode::Prefix("sensor#".into()),
+            group_by: Some(GroupBy::Delimiter("#".into())),
+            group_limit: Some(1),
+        },
+        column: None,
+        cell: Some(AggregateCellOptions {
+            time: Some(TimeRange {
+                start: Some(1),
+                end: Some(4),
+            }),
+            all_versions: false,
+        }),
+        ..Default::default()
+    })?;
+
+    assert!(result.is_group_limit_reached);
+    assert_eq!(1, result.groups.len());
+    assert_eq!(2, result.row_count);
+
+    let temp = &result.groups[0].columns["value:temp"];
+    assert_eq!("sensor#a#", result.groups[0].key);
+    assert_eq!(2, temp.count);
+    assert_eq!(16.0, temp.sum);
+
+    Ok(())
+}
@@ -0,0 +1,280 @@
+mod common;
+
+use smoltable::{
+    query::row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+    CellValue, ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions,
+    SchemaMode, Smoltable, TableWriter,
+};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use test_log::test;
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+    common::create_column_family(&table, "another", Some(true))?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["a", "b"] {
+        writer.write(&smoltable::row!(
+            row_key,
+            vec![
+                smoltable::cell!("value:asd", Some(0), CellValue::I32(0)),
+                smoltable::cell!("another:asd", Some(0), CellValue::I32(1)),
+                smoltable::cell!("another:def", Some(0), CellValue::I32(2))
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+fn get_row_cell_count(table: &Smoltable, row_key: &str) -> smoltable::Result<usize> {
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: row_key.to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+
+    Ok(query_result
+        .row
+        .map(|row| row.cell_count())
+        .unwrap_or_default())
+}
+
+#[test]
+pub fn delete_column_family() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    assert_eq!(1, table.locality_groups.read().unwrap().len());
+
+    assert_eq!(4, table.delete_column_family("another")?);
+
+    assert_eq!(1, get_row_cell_count(&table, "a")?);
+    assert_eq!(1, get_row_cell_count(&table, "b")?);
+
+    assert_eq!(1, table.list_column_families()?.len());
+    assert!(table.locality_groups.read().unwrap().is_empty());
+
+    assert!(matches!(
+        table.delete_column_family("another"),
+        Err(smoltable::Error::ColumnFamilyNotFound(_))
+    ));
+
+    // NOTE: The column family is no longer defined
+    assert!(matches!(
+        TableWriter::write_batch(
+            table.clone(),
+            &[smoltable::row!(
+                "a",
+                vec![smoltable::cell!("another:asd", Some(0), CellValue::I32(1))]
+            )]
+        ),
+        Err(smoltable::Error::UndefinedColumnFamilies(_))
+    ));
+
+    assert_eq!(2, table.delete_column_family("value")?);
+    assert_eq!(0, get_row_cell_count(&table, "a")?);
+    assert_eq!(0, table.list_column_families()?.len());
+
+    Ok(())
+}
+
+#[test]
+pub fn update_column_family() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    {
+        let table = create_table(folder.path())?;
+
+        table.update_column_family(
+            "value",
+            GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: Some(1),
+            },
+        )?;
+
+        assert!(matches!(
+            table.update_column_family("missing", GarbageCollectionOptions::default()),
+            Err(smoltable::Error::ColumnFamilyNotFound(_))
+        ));
+    }
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace)?;
+
+    let column_families = table.list_column_families()?;
+    let value = column_families
+        .iter()
+        .find(|cf| cf.name == "value")
+        .unwrap();
+    assert_eq!(Some(1), value.gc_settings.version_limit);
+
+    Ok(())
+}
+
+#[test]
+pub fn delete_column_family_shared_locality_group() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace)?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: ["value", "another"]
+            .into_iter()
+            .map(|name| ColumnFamilyDefinition {
+                name: name.to_owned(),
+                gc_settings: GarbageCollectionOptions {
+                    ttl_secs: None,
+                    version_limit: None,
+                },
+                ..Default::default()
+            })
+            .collect(),
+        locality_group: Some(true),
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for idx in 0..12_000 {
+        writer.write(&smoltable::row!(
+            &format!("row{idx:05}"),
+            vec![smoltable::cell!(
+                "another:asd",
+                Some(0),
+                CellValue::I32(idx)
+            )]
+        ))?;
+    }
+
+    writer.write(&smoltable::row!(
+        "row00000",
+        vec![smoltable::cell!("value:asd", Some(0), CellValue::I32(0))]
+    ))?;
+
+    writer.finalize()?;
+
+    // NOTE: Cells are deleted in multiple batches
+    assert_eq!(12_000, table.delete_column_family("another")?);
+
+    let locality_groups = table.locality_groups.read().unwrap();
+    assert_eq!(1, locality_groups.len());
+    assert_eq!(1, locality_groups[0].tree.len()?);
+    drop(locality_groups);
+
+    assert_eq!(1, get_row_cell_count(&table, "row00000")?);
+    assert_eq!(0, get_row_cell_count(&table, "row00001")?);
+
+    Ok(())
+}
+
+#[test]
+pub fn delete_column_family_permissive_concurrent_writes() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace)?;
+    table.set_schema_mode(SchemaMode::Permissive)?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "another".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for idx in 0..30_000 {
+        writer.write(&smoltable::row!(
+            &format!("m{idx:05}"),
+            vec![smoltable::cell!(
+                "another:asd",
+                Some(0),
+                CellValue::I32(idx)
+            )]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    let is_done = Arc::new(AtomicBool::new(false));
+
+    let writer_thread = {
+        let table = table.clone();
+        let is_done = is_done.clone();
+
+        std::thread::spawn(move || -> smoltable::Result<i32> {
+            let mut idx = 0;
+
+            while !is_done.load(Ordering::Acquire) {
+                // NOTE: The rows are at both ends of the table, so a batch could
+                // straddle the delete cursor
+                let result = TableWriter::write_batch(
+                    table.clone(),
+                    &[
+                        smoltable::row!(
+                            &format!("a{idx:05}"),
+                            vec![smoltable::cell!(
+                                "another:asd",
+                                Some(0),
+                                CellValue::I32(idx)
+                            )]
+                        ),
+                        smoltable::row!(
+                            &format!("z{idx:05}"),
+                            vec![smoltable::cell!(
+                                "another:asd",
+                                Some(0),
+                                CellValue::I32(idx)
+                            )]
+                        ),
+                    ],
+                );
+
+                match result {
+                    Ok(()) | Err(smoltable::Error::UndefinedColumnFamilies(_)) => {}
+                    Err(e) => return Err(e),
+                }
+
+                idx += 1;
+            }
+
+            Ok(idx)
+        })
+    };
+
+    table.delete_column_family("another")?;
+    is_done.store(true, Ordering::Release);
+
+    let written_count = writer_thread.join().expect("should join")?;
+
+    // NOTE: Writes during the delete were either rejected or deleted as a whole
+    for idx in 0..written_count {
+        assert_eq!(
+            get_row_cell_count(&table, &format!("a{idx:05}"))?,
+            get_row_cell_count(&table, &format!("z{idx:05}"))?
+        );
+    }
+
+    for idx in [0, 29_999] {
+        assert_eq!(0, get_row_cell_count(&table, &format!("m{idx:05}"))?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,424 @@
+mod common;
+
+use smoltable::{
+    query::{
+        check_and_mutate::{CompareOp, Comparison, Input as CheckAndMutateInput, Predicate},
+        row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+    },
+    CellValue, ColumnKey, DeleteWriteItem, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn get_latest_value(table: &Smoltable, row_key: &str, column: &str) -> Option<CellValue> {
+    let query_result = table
+        .get_row(QueryRowInput {
+            row: QueryRowInputRowOptions {
+                key: row_key.to_owned(),
+                cell_limit: None,
+            },
+            column: None,
+            ..Default::default()
+        })
+        .unwrap();
+
+    let column_key = ColumnKey::try_from(column).unwrap();
+
+    query_result
+        .row?
+        .columns
+        .get(&column_key.family)?
+        .get(column_key.qualifier.as_deref().unwrap_or_default())?
+        .first()
+        .map(|cell| cell.value.clone())
+}
+
+#[test]
+pub fn check_and_mutate_exists() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    let input = || CheckAndMutateInput {
+        row_key: "user1".into(),
+        predicate: Predicate::Exists(ColumnKey::try_from("value:name").unwrap()),
+        true_mutations: vec![smoltable::cell!(
+            "value:status",
+            None,
+            CellValue::String("exists".into())
+        )],
+        true_deletes: vec![],
+        false_mutations: vec![smoltable::cell!(
+            "value:name",
+            None,
+            CellValue::String("peter".into())
+        )],
+        false_deletes: vec![],
+    };
+
+    let result = table.check_and_mutate(input())?;
+    assert!(!result.predicate_matched);
+    assert_eq!(
+        Some(CellValue::String("peter".into())),
+        get_latest_value(&table, "user1", "value:name")
+    );
+    assert_eq!(None, get_latest_value(&table, "user1", "value:status"));
+
+    let result = table.check_and_mutate(input())?;
+    assert!(result.predicate_matched);
+    assert_eq!(
+        Some(CellValue::String("exists".into())),
+        get_latest_value(&table, "user1", "value:status")
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn check_and_mutate_compare() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    let mut writer = smoltable::TableWriter::new(table.clone());
+    writer.write(&smoltable::row!(
+        "user1",
+        vec![
+            smoltable::cell!("value:version", Some(0), CellValue::I32(1)),
+            smoltable::cell!("value:version", Some(1), CellValue::I32(2))
+        ]
+    ))?;
+    writer.finalize()?;
+
+    let compare = |op, value| CheckAndMutateInput {
+        row_key: "user1".into(),
+        predicate: Predicate::Compare(Comparison {
+            column_key: ColumnKey::try_from("value:version").unwrap(),
+            op,
+            value,
+        }),
+        true_mutations: vec![],
+        true_deletes: vec![],
+        false_mutations: vec![],
+        false_deletes: vec![],
+    };
+
+    // NOTE: Only the latest version is compared
+    assert!(
+        !table
+            .check_and_mutate(compare(CompareOp::Equal, CellValue::I32(1)))?
+            .predicate_matched
+    );
+    assert!(
+        table
+            .check_and_mutate(compare(CompareOp::Equal, CellValue::I32(2)))?
+            .predicate_matched
+    );
+    assert!(
+        table
+            .check_and_mutate(compare(CompareOp::NotEqual, CellValue::I32(1)))?
+            .predicate_matched
+    );
+    assert!(
+        table
+            .check_and_mutate(compare(CompareOp::Greater, CellValue::I32(1)))?
+            .predicate_matched
+    );
+    assert!(
+        table
+            .check_and_mutate(compare(CompareOp::GreaterOrEqual, CellValue::I32(2)))?
+            .predicate_matched
+    );
+    assert!(
+        !table
+            .check_and_mutate(compare(CompareOp::Less, CellValue::I32(2)))?
+            .predicate_matched
+    );
+    assert!(
+        table
+            .check_and_mutate(compare(CompareOp::LessOrEqual, CellValue::I32(2)))?
+            .predicate_matched
+    );
+
+    // NOTE: Values of different types never match
+    assert!(
+        !table
+            .check_and_mutate(compare(CompareOp::Equal, CellValue::I64(2)))?
+            .predicate_matched
+    );
+    assert!(
+        !table
+            .check_and_mutate(compare(CompareOp::NotEqual, CellValue::I64(2)))?
+            .predicate_matched
+    );
+
+    // NOTE: Missing columns never match
+    let mut input = compare(CompareOp::NotEqual, CellValue::I32(2));
+    input.row_key = "user2".into();
+    assert!(!table.check_and_mutate(input)?.predicate_matched);
+
+    Ok(())
+}
+
+#[test]
+pub fn check_and_mutate_concurrent() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    let threads = (0..8)
+        .map(|idx| {
+            let table = table.clone();
+
+            std::thread::spawn(move || {
+                table
+                    .check_and_mutate(CheckAndMutateInput {
+                        row_key: "lock".into(),
+                        predicate: Predicate::Exists(ColumnKey::try_from("value:owner").unwrap()),
+                        true_mutations: vec![],
+                        true_deletes: vec![],
+                        false_mutations: vec![smoltable::cell!(
+                            "value:owner",
+                            None,
+                            CellValue::I32(idx)
+                        )],
+                        false_deletes: vec![],
+                    })
+                    .unwrap()
+                    .predicate_matched
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let winner_count = threads
+        .into_iter()
+        .map(|thread| thread.join().unwrap())
+        .filter(|matched| !matched)
+        .count();
+
+    // NOTE: Only one thread can claim the lock
+    assert_eq!(1, winner_count);
+
+    Ok(())
+}
+
+#[test]
+pub fn check_and_mutate_compare_json() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    let mut writer = smoltable::TableWriter::new(table.clone());
+    writer.write(&smoltable::row!(
+        "user1",
+        vec![smoltable::cell!(
+            "value:settings",
+            Some(0),
+            CellValue::Json(serde_json::json!({ "theme": "dark" }))
+        )]
+    ))?;
+    writer.finalize()?;
+
+    let compare = |op, value| CheckAndMutateInput {
+        row_key: "user1".into(),
+        predicate: Predicate::Compare(Comparison {
+            column_key: ColumnKey::try_from("value:settings").unwrap(),
+            op,
+            value,
+        }),
+        true_mutations: vec![],
+        true_deletes: vec![],
+        false_mutations: vec![],
+        false_deletes: vec![],
+    };
+
+    let dark = CellValue::Json(serde_json::json!({ "theme": "dark" }));
+    let light = CellValue::Json(serde_json::json!({ "theme": "light" }));
+
+    assert!(
+        table
+            .check_and_mutate(compare(CompareOp::Equal, dark.clone()))?
+            .predicate_matched
+    );
+    assert!(
+        table
+            .check_and_mutate(compare(CompareOp::NotEqual, light.clone()))?
+            .predicate_matched
+    );
+
+    // NOTE: JSON documents have no order
+    assert!(
+        !table
+            .check_and_mutate(compare(CompareOp::GreaterOrEqual, light))?
+            .predicate_matched
+    );
+    assert!(
+        !table
+            .check_and_mutate(compare(CompareOp::Less, dark))?
+            .predicate_matched
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn check_and_mutate_delete() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "lock",
+            vec![
+                smoltable::cell!("value:owner", Some(0), CellValue::I32(1)),
+                smoltable::cell!("value:owner", Some(1), CellValue::I32(2)),
+                smoltable::cell!("value:lease", Some(0), CellValue::I32(60))
+            ]
+        )],
+    )?;
+
+    // NOTE: Release the lock, if it is owned by worker 2
+    let input = || CheckAndMutateInput {
+        row_key: "lock".into(),
+        predicate: Predicate::Compare(Comparison {
+            column_key: ColumnKey::try_from("value:owner").unwrap(),
+            op: CompareOp::Equal,
+            value: CellValue::I32(2),
+        }),
+        true_mutations: vec![smoltable::cell!(
+            "value:released",
+            None,
+            CellValue::Boolean(true)
+        )],
+        true_deletes: vec![
+            DeleteWriteItem::Column {
+                column_key: ColumnKey::try_from("value:owner").unwrap(),
+                time: None,
+            },
+            DeleteWriteItem::Column {
+                column_key: ColumnKey::try_from("value:lease").unwrap(),
+                time: None,
+            },
+        ],
+        false_mutations: vec![],
+        false_deletes: vec![],
+    };
+
+    assert!(table.check_and_mutate(input())?.predicate_matched);
+    assert_eq!(None, get_latest_value(&table, "lock", "value:owner"));
+    assert_eq!(None, get_latest_value(&table, "lock", "value:lease"));
+    assert_eq!(
+        Some(CellValue::Boolean(true)),
+        get_latest_value(&table, "lock", "value:released")
+    );
+
+    assert!(!table.check_and_mutate(input())?.predicate_matched);
+
+    // NOTE: Deletes can be applied if the predicate does not match as well
+    let result = table.check_and_mutate(CheckAndMutateInput {
+        row_key: "lock".into(),
+        predicate: Predicate::Exists(ColumnKey::try_from("value:owner").unwrap()),
+        true_mutations: vec![],
+        true_deletes: vec![],
+        false_mutations: vec![],
+        false_deletes: vec![DeleteWriteItem::Row],
+    })?;
+    assert!(!result.predicate_matched);
+    assert_eq!(None, get_latest_value(&table, "lock", "value:released"));
+
+    Ok(())
+}
+
+#[test]
+pub fn check_and_mutate_concurrent_writes() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "counter",
+            vec![smoltable::cell!("value:count", Some(0), CellValue::I64(0))]
+        )],
+    )?;
+
+    let writer_thread = {
+        let table = table.clone();
+
+        std::thread::spawn(move || -> smoltable::Result<()> {
+            for idx in 0..200 {
+                TableWriter::write_batch(
+                    table.clone(),
+                    &[
+                        smoltable::row!(
+                            "counter",
+                            vec![smoltable::cell!("value:other", None, CellValue::I32(idx))]
+                        ),
+                        smoltable::row!(
+                            "other",
+                            vec![smoltable::cell!("value:other", None, CellValue::I32(idx))]
+                        ),
+                    ],
+                )?;
+            }
+
+            Ok(())
+        })
+    };
+
+    let threads = (0..4)
+        .map(|_| {
+            let table = table.clone();
+
+            std::thread::spawn(move || -> smoltable::Result<i64> {
+                let mut success_count = 0;
+
+                for _ in 0..50 {
+                    let Some(CellValue::I64(count)) =
+                        get_latest_value(&table, "counter", "value:count")
+                    else {
+                        panic!("counter should exist");
+                    };
+
+                    let result = table.check_and_mutate(CheckAndMutateInput {
+                        row_key: "counter".into(),
+                        predicate: Predicate::Compare(Comparison {
+                            column_key: ColumnKey::try_from("value:count").unwrap(),
+                            op: CompareOp::Equal,
+                            value: CellValue::I64(count),
+                        }),
+                        true_mutations: vec![smoltable::cell!(
+                            "value:count",
+                            None,
+                            CellValue::I64(count + 1)
+                        )],
+                        true_deletes: vec![],
+                        false_mutations: vec![],
+                        false_deletes: vec![],
+                    })?;
+
+                    if result.predicate_matched {
+                        success_count += 1;
+                    }
+                }
+
+                Ok(success_count)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut success_count = 0;
+
+    for thread in threads {
+        success_count += thread.join().expect("should join")?;
+    }
+
+    writer_thread.join().expect("should join")?;
+
+    assert_eq!(
+        Some(CellValue::I64(success_count)),
+        get_latest_value(&table, "counter", "value:count")
+    );
+    assert_eq!(
+        Some(CellValue::I32(199)),
+        get_latest_value(&table, "counter", "value:other")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// NOTE: Every test binary includes this module, but only uses some of the helpers
+#![allow(dead_code)]
+
+use smoltable::{
+    ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions, Smoltable,
+};
+
+/// Opens the table "test" in a new keyspace
+pub fn open_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let keyspace = fjall::Config::new(folder).open()?;
+    Smoltable::open("test", keyspace)
+}
+
+/// Creates a column family without garbage collection,
+/// in a new locality group if `locality_group` is set
+pub fn create_column_family(
+    table: &Smoltable,
+    name: &str,
+    locality_group: Option<bool>,
+) -> smoltable::Result<()> {
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: name.to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group,
+        ..Default::default()
+    })
+}
+
+/// Opens the table "test" with a "value" column family in the default locality group
+pub fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = open_table(folder)?;
+    create_column_family(&table, "value", None)?;
+    Ok(table)
+}
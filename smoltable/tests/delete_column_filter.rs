@@ -20,8 +20,10 @@ pub fn delete_column_filter() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -85,8 +87,10 @@ pub fn delete_column_family() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -137,8 +141,10 @@ pub fn delete_multi_columns() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -192,8 +198,10 @@ pub fn delete_prefix_columns() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -0,0 +1,100 @@
+mod common;
+
+use smoltable::{
+    query::row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+    CellValue, ColumnFilter, ColumnKey, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+    common::create_column_family(&table, "another", Some(true))?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["a", "b"] {
+        writer.write(&smoltable::row!(
+            row_key,
+            vec![
+                smoltable::cell!("value:asd", Some(0), CellValue::I32(0)),
+                smoltable::cell!("another:asd", Some(0), CellValue::I32(1)),
+                smoltable::cell!("another:def", Some(0), CellValue::I32(2))
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+fn get_row_cell_count(table: &Smoltable, row_key: &str) -> smoltable::Result<usize> {
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: row_key.to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+
+    Ok(query_result
+        .row
+        .map(|row| row.cell_count())
+        .unwrap_or_default())
+}
+
+#[test]
+pub fn delete_row_multiple_locality_groups() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    assert_eq!(3, get_row_cell_count(&table, "a")?);
+
+    let deleted_count = table.delete_row("a".to_string(), None)?;
+    assert_eq!(3, deleted_count);
+
+    assert_eq!(0, get_row_cell_count(&table, "a")?);
+    assert_eq!(3, get_row_cell_count(&table, "b")?);
+
+    // NOTE: Nothing left to delete
+    let deleted_count = table.delete_row("a".to_string(), None)?;
+    assert_eq!(0, deleted_count);
+
+    Ok(())
+}
+
+#[test]
+pub fn delete_column_filter_multiple_locality_groups() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let deleted_count = table.delete_row(
+        "a".to_string(),
+        Some(ColumnFilter::Key(
+            ColumnKey::try_from("another:def").unwrap(),
+        )),
+    )?;
+    assert_eq!(1, deleted_count);
+    assert_eq!(2, get_row_cell_count(&table, "a")?);
+
+    let deleted_count = table.delete_row(
+        "a".to_string(),
+        Some(ColumnFilter::Prefix(
+            ColumnKey::try_from("another:").unwrap(),
+        )),
+    )?;
+    assert_eq!(1, deleted_count);
+    assert_eq!(1, get_row_cell_count(&table, "a")?);
+
+    let deleted_count = table.delete_row(
+        "a".to_string(),
+        Some(ColumnFilter::Key(ColumnKey::try_from("value:asd").unwrap())),
+    )?;
+    assert_eq!(1, deleted_count);
+    assert_eq!(0, get_row_cell_count(&table, "a")?);
+
+    assert_eq!(3, get_row_cell_count(&table, "b")?);
+
+    Ok(())
+}
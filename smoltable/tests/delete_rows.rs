@@ -0,0 +1,163 @@
+mod common;
+
+use smoltable::{
+    query::{
+        delete::{DeleteMode, Input as DeleteInput, RowOptions as DeleteRowOptions},
+        scan::Range,
+    },
+    CellValue, ColumnFilter, ColumnKey, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+    common::create_column_family(&table, "another", Some(true))?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for tenant in ["tenant1", "tenant2", "tenant3"] {
+        for idx in 0..5 {
+            writer.write(&smoltable::row!(
+                &format!("{tenant}#{idx}"),
+                vec![
+                    smoltable::cell!("value:a", Some(0), CellValue::I32(idx)),
+                    smoltable::cell!("another:a", Some(0), CellValue::I32(idx))
+                ]
+            ))?;
+        }
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+#[test]
+pub fn delete_rows_keys() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let result = table.delete_rows(DeleteInput {
+        row: DeleteRowOptions {
+            mode: DeleteMode::Keys(vec![
+                "tenant1#3".into(),
+                "tenant2#0".into(),
+                "tenant1#3".into(),
+                "nothing".into(),
+            ]),
+            cell_limit: None,
+            cursor: None,
+        },
+        column_filter: None,
+        ..Default::default()
+    })?;
+
+    assert_eq!(4, result.deleted_cells_count);
+    assert!(result.cursor.is_none());
+
+    let (row_count, cell_count) = table.count()?;
+    assert_eq!(13, row_count);
+    assert_eq!(26, cell_count);
+
+    Ok(())
+}
+
+#[test]
+pub fn delete_rows_prefix() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let result = table.delete_rows(DeleteInput {
+        row: DeleteRowOptions {
+            mode: DeleteMode::Prefix("tenant2#".into()),
+            cell_limit: None,
+            cursor: None,
+        },
+        column_filter: None,
+        ..Default::default()
+    })?;
+
+    assert_eq!(10, result.deleted_cells_count);
+    assert!(result.cursor.is_none());
+
+    let (row_count, cell_count) = table.count()?;
+    assert_eq!(10, row_count);
+    assert_eq!(20, cell_count);
+
+    Ok(())
+}
+
+#[test]
+pub fn delete_rows_range_column_filter() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let result = table.delete_rows(DeleteInput {
+        row: DeleteRowOptions {
+            mode: DeleteMode::Range(Range {
+                start: "tenant1#3".into(),
+                end: "tenant2#2".into(),
+                inclusive: false,
+            }),
+            cell_limit: None,
+            cursor: None,
+        },
+        column_filter: Some(ColumnFilter::Key(ColumnKey::try_from("another:a").unwrap())),
+        ..Default::default()
+    })?;
+
+    assert_eq!(4, result.deleted_cells_count);
+
+    let (row_count, cell_count) = table.count()?;
+    assert_eq!(15, row_count);
+    assert_eq!(26, cell_count);
+
+    Ok(())
+}
+
+#[test]
+pub fn delete_rows_chunked() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    for mode in [
+        DeleteMode::Prefix("tenant3#".into()),
+        DeleteMode::Keys(vec![
+            "tenant1#0".into(),
+            "tenant1#1".into(),
+            "tenant1#2".into(),
+        ]),
+    ] {
+        let mut cursor = None;
+        let mut batch_count = 0;
+
+        loop {
+            let result = table.delete_rows(DeleteInput {
+                row: DeleteRowOptions {
+                    mode: mode.clone(),
+                    cell_limit: Some(3),
+                    cursor: cursor.clone(),
+                },
+                column_filter: None,
+                ..Default::default()
+            })?;
+
+            batch_count += 1;
+            assert!(result.deleted_cells_count <= 3);
+
+            cursor = result.cursor;
+
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        assert!(batch_count > 1);
+    }
+
+    let (row_count, cell_count) = table.count()?;
+    assert_eq!(7, row_count);
+    assert_eq!(14, cell_count);
+
+    Ok(())
+}
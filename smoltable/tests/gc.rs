@@ -21,8 +21,10 @@ pub fn gc_version_limit() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: Some(3),
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -48,6 +50,7 @@ pub fn gc_version_limit() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
     assert_eq!(query_result.cells_scanned_count, 5);
 
@@ -59,6 +62,7 @@ pub fn gc_version_limit() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
     assert_eq!(query_result.cells_scanned_count, 3);
 
@@ -81,8 +85,10 @@ pub fn gc_ttl() -> smoltable::Result<()> {
                 ttl_secs: Some(5),
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -118,6 +124,7 @@ pub fn gc_ttl() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -131,6 +138,7 @@ pub fn gc_ttl() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -0,0 +1,192 @@
+use smoltable::{
+    query::{
+        row::{
+            ColumnOptions as QueryRowInputColumnOptions, Input as QueryRowInput,
+            RowOptions as QueryRowInputRowOptions,
+        },
+        scan::{Input as QueryPrefixInput, RowOptions as QueryPrefixRowOptions, ScanMode},
+    },
+    CellValue, ColumnFamilyDefinition, ColumnKey, ColumnWriteItem, CreateColumnFamilyInput,
+    GarbageCollectionOptions, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn write_rows(table: &Smoltable) -> smoltable::Result<()> {
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["user:1", "user:1:", "user:12", "user:1\0"] {
+        writer.write(&smoltable::row!(
+            row_key,
+            vec![ColumnWriteItem {
+                column_key: ColumnKey {
+                    family: "value".to_owned(),
+                    qualifier: Some("a:b".to_owned()),
+                },
+                timestamp: Some(0),
+                value: CellValue::String(row_key.to_owned()),
+            }]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    Ok(())
+}
+
+#[test]
+pub fn key_delimiters_read_row() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    write_rows(&table)?;
+
+    for row_key in ["user:1", "user:1:", "user:12", "user:1\0"] {
+        let query_result = table.get_row(QueryRowInput {
+            row: QueryRowInputRowOptions {
+                key: row_key.to_owned(),
+                cell_limit: None,
+            },
+            column: None,
+            ..Default::default()
+        })?;
+
+        let row = query_result.row.unwrap();
+        assert_eq!(row_key, row.row_key);
+        assert_eq!(1, row.cell_count());
+
+        let cell = &row.columns["value"]["a:b"][0];
+        assert_eq!(CellValue::String(row_key.to_owned()), cell.value);
+    }
+
+    Ok(())
+}
+
+#[test]
+pub fn key_delimiters_scan() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    write_rows(&table)?;
+
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix("user:1".to_owned()),
+            cell_limit: None,
+            offset: None,
+            limit: None,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(
+        vec!["user:1", "user:1\0", "user:12", "user:1:"],
+        query_result
+            .rows
+            .iter()
+            .map(|row| row.row_key.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix("user:1:".to_owned()),
+            cell_limit: None,
+            offset: None,
+            limit: None,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(1, query_result.rows.len());
+    assert_eq!("user:1:", query_result.rows[0].row_key);
+
+    Ok(())
+}
+
+#[test]
+pub fn key_delimiters_parsed_column_key() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+    writer.write(&smoltable::row!(
+        "a",
+        vec![
+            smoltable::cell!("value:user:123", Some(0), CellValue::I32(0)),
+            smoltable::cell!("value:user", Some(0), CellValue::I32(1)),
+        ]
+    ))?;
+    writer.finalize()?;
+
+    // NOTE: Column keys are parsed from JSON, as a client would send them
+    let column: QueryRowInputColumnOptions =
+        serde_json::from_str(r#"{ "key": "value:user:123" }"#).expect("should deserialize");
+
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: "a".to_owned(),
+            cell_limit: None,
+        },
+        column: Some(column),
+        ..Default::default()
+    })?;
+
+    let row = query_result.row.unwrap();
+    assert_eq!(1, row.cell_count());
+    assert_eq!(CellValue::I32(0), row.columns["value"]["user:123"][0].value);
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use smoltable::{
+    query::row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+    CellValue, ColumnFamilyDefinition, CompactionOptions, CreateColumnFamilyInput,
+    GarbageCollectionOptions, LocalityGroupOptions, Smoltable, TableWriter,
+};
+use test_log::test;
+
+#[test]
+pub fn locality_group_options() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    {
+        let keyspace = fjall::Config::new(folder.path()).open()?;
+        let table = Smoltable::open("test", keyspace.clone())?;
+
+        table.create_column_families(&CreateColumnFamilyInput {
+            column_families: vec![ColumnFamilyDefinition {
+                name: "value".to_owned(),
+                gc_settings: GarbageCollectionOptions {
+                    ttl_secs: None,
+                    version_limit: None,
+                },
+                ..Default::default()
+            }],
+            locality_group: Some(true),
+            locality_group_options: Some(LocalityGroupOptions {
+                compaction: Some(CompactionOptions::Tiered {
+                    base_size: 1_024 * 1_024,
+                }),
+                block_size: Some(4_096),
+                max_memtable_size: Some(1_024 * 1_024),
+            }),
+        })?;
+
+        TableWriter::write_batch(
+            table.clone(),
+            &[smoltable::row!(
+                "a",
+                vec![smoltable::cell!("value:", Some(0), CellValue::I32(0))]
+            )],
+        )?;
+    }
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    {
+        let locality_groups = table.locality_groups.read().unwrap();
+        assert_eq!(1, locality_groups.len());
+
+        let options = &locality_groups[0].options;
+        assert_eq!(Some(4_096), options.block_size);
+        assert_eq!(Some(1_024 * 1_024), options.max_memtable_size);
+        assert!(matches!(
+            options.compaction,
+            Some(CompactionOptions::Tiered { base_size }) if base_size == 1_024 * 1_024
+        ));
+    }
+
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: "a".to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+    assert_eq!(1, query_result.row.unwrap().cell_count());
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use smoltable::{
+    query::row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+    CellValue, ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions,
+    Smoltable,
+};
+use test_log::test;
+
+/// Formats a cell key in the legacy `row_key:cf:cq:!ts` format
+fn legacy_key(row_key: &str, family: &str, qualifier: &str, timestamp: u128) -> Vec<u8> {
+    let mut key = format!("{row_key}:{family}:{qualifier}:").into_bytes();
+    key.extend_from_slice(&(!timestamp).to_be_bytes());
+    key
+}
+
+#[test]
+pub fn migrate_key_format() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    {
+        let keyspace = fjall::Config::new(folder.path()).open()?;
+        let table = Smoltable::open("test", keyspace.clone())?;
+        assert_eq!(2, table.key_format_version()?);
+
+        table.create_column_families(&CreateColumnFamilyInput {
+            column_families: vec![ColumnFamilyDefinition {
+                name: "value".to_owned(),
+                gc_settings: GarbageCollectionOptions {
+                    ttl_secs: None,
+                    version_limit: None,
+                },
+                ..Default::default()
+            }],
+            locality_group: None,
+            ..Default::default()
+        })?;
+
+        // NOTE: Simulate a table written before the format marker existed
+        table.manifest.remove("format#version")?;
+
+        for (row_key, qualifier, timestamp) in [("a", "", 0), ("a", "x", 1), ("user:1", "", 2)] {
+            table.tree.insert(
+                legacy_key(row_key, "value", qualifier, timestamp),
+                CellValue::U64(timestamp as u64).to_bytes(),
+            )?;
+        }
+
+        keyspace.persist(fjall::PersistMode::SyncAll)?;
+    }
+
+    {
+        let keyspace = fjall::Config::new(folder.path()).open()?;
+        let table = Smoltable::open("test", keyspace.clone())?;
+        assert_eq!(2, table.key_format_version()?);
+
+        let query_result = table.get_row(QueryRowInput {
+            row: QueryRowInputRowOptions {
+                key: "a".to_owned(),
+                cell_limit: None,
+            },
+            column: None,
+            ..Default::default()
+        })?;
+
+        let row = query_result.row.unwrap();
+        assert_eq!(2, row.cell_count());
+        assert_eq!(CellValue::U64(1), row.columns["value"]["x"][0].value);
+
+        let query_result = table.get_row(QueryRowInput {
+            row: QueryRowInputRowOptions {
+                key: "user:1".to_owned(),
+                cell_limit: None,
+            },
+            column: None,
+            ..Default::default()
+        })?;
+
+        let row = query_result.row.unwrap();
+        assert_eq!(1, row.cell_count());
+        assert_eq!(2, row.columns["value"][""][0].timestamp);
+
+        assert_eq!(3, table.tree.len()?);
+    }
+
+    Ok(())
+}
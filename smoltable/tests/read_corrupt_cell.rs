@@ -0,0 +1,89 @@
+use smoltable::{
+    query::{
+        row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+        scan::{Input as QueryPrefixInput, RowOptions as QueryPrefixRowOptions, ScanMode},
+    },
+    CellValue, ColumnFamilyDefinition, ColumnKey, CreateColumnFamilyInput,
+    GarbageCollectionOptions, Smoltable, TableWriter, VisitedCell,
+};
+use test_log::test;
+
+#[test]
+pub fn read_corrupt_cell() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["a", "b", "c"] {
+        writer.write(&smoltable::row!(
+            row_key,
+            vec![smoltable::cell!("value:", Some(0), CellValue::I32(0))]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    // NOTE: Write a value with an unknown type tag
+    let corrupt_key = VisitedCell::format_key("b", &ColumnKey::try_from("value:x").unwrap(), 0);
+    table.tree.insert(&corrupt_key, [99])?;
+
+    let result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: "b".to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    });
+
+    match result {
+        Err(smoltable::Error::Decode(key)) => assert_eq!(*corrupt_key, *key),
+        other => panic!("expected decode error, got {other:?}"),
+    }
+
+    let result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix(String::new()),
+            cell_limit: None,
+            offset: None,
+            limit: None,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    assert!(matches!(result, Err(smoltable::Error::Decode(_))));
+
+    // NOTE: Other rows can still be read
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: "c".to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+
+    assert_eq!(1, query_result.row.unwrap().cell_count());
+
+    Ok(())
+}
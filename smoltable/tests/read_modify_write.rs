@@ -0,0 +1,209 @@
+mod common;
+
+use smoltable::{
+    query::{
+        read_modify_write::{Input as ReadModifyWriteInput, Operation, Rule},
+        row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+    },
+    CellValue, ColumnKey, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn latest_value(row: &smoltable::Row, family: &str, qualifier: &str) -> CellValue {
+    row.columns[family][qualifier][0].value.clone()
+}
+
+fn stored_cell_count(table: &Smoltable, row_key: &str) -> smoltable::Result<usize> {
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: row_key.to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+
+    Ok(query_result
+        .row
+        .map(|row| row.cell_count())
+        .unwrap_or_default())
+}
+
+fn rule(column: &str, operation: Operation) -> Rule {
+    Rule {
+        column_key: ColumnKey::try_from(column).unwrap(),
+        operation,
+    }
+}
+
+#[test]
+pub fn read_modify_write_increment_append() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    // NOTE: Written with a timestamp in the future
+    let mut writer = TableWriter::new(table.clone());
+    writer.write(&smoltable::row!(
+        "counter",
+        vec![smoltable::cell!(
+            "value:views",
+            Some(u128::MAX - 100),
+            CellValue::I64(10)
+        )]
+    ))?;
+    writer.finalize()?;
+
+    let result = table.read_modify_write(ReadModifyWriteInput {
+        row_key: "counter".into(),
+        rules: vec![
+            rule("value:views", Operation::Increment(CellValue::I64(5))),
+            rule("value:views", Operation::Increment(CellValue::I64(-2))),
+            rule("value:clicks", Operation::Increment(CellValue::I32(1))),
+            rule("value:score", Operation::Increment(CellValue::F64(0.5))),
+            rule("value:log", Operation::Append("a".into())),
+            rule("value:log", Operation::Append("b".into())),
+        ],
+    })?;
+
+    let row = &result.row;
+    assert_eq!(CellValue::I64(13), latest_value(row, "value", "views"));
+    assert_eq!(CellValue::I32(1), latest_value(row, "value", "clicks"));
+    assert_eq!(CellValue::F64(0.5), latest_value(row, "value", "score"));
+    assert_eq!(
+        CellValue::String("ab".into()),
+        latest_value(row, "value", "log")
+    );
+    assert_eq!(4, row.cell_count());
+
+    // NOTE: Rules on the same column compose into one new version
+    assert_eq!(5, stored_cell_count(&table, "counter")?);
+
+    let result = table.read_modify_write(ReadModifyWriteInput {
+        row_key: "counter".into(),
+        rules: vec![
+            rule("value:views", Operation::Increment(CellValue::I64(1))),
+            rule("value:log", Operation::Append("c".into())),
+        ],
+    })?;
+
+    let row = &result.row;
+    assert_eq!(CellValue::I64(14), latest_value(row, "value", "views"));
+    assert_eq!(
+        CellValue::String("abc".into()),
+        latest_value(row, "value", "log")
+    );
+    assert_eq!(7, stored_cell_count(&table, "counter")?);
+
+    Ok(())
+}
+
+#[test]
+pub fn read_modify_write_invalid_type() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    table.read_modify_write(ReadModifyWriteInput {
+        row_key: "counter".into(),
+        rules: vec![rule("value:views", Operation::Increment(CellValue::I64(1)))],
+    })?;
+
+    for operation in [
+        Operation::Increment(CellValue::I32(1)),
+        Operation::Append("a".into()),
+    ] {
+        let result = table.read_modify_write(ReadModifyWriteInput {
+            row_key: "counter".into(),
+            rules: vec![rule("value:views", operation)],
+        });
+
+        assert!(matches!(result, Err(smoltable::Error::InvalidValueType(_))));
+    }
+
+    let result = table.read_modify_write(ReadModifyWriteInput {
+        row_key: "counter".into(),
+        rules: vec![rule(
+            "value:new",
+            Operation::Increment(CellValue::String("a".into())),
+        )],
+    });
+
+    assert!(matches!(result, Err(smoltable::Error::InvalidValueType(_))));
+
+    Ok(())
+}
+
+#[test]
+pub fn read_modify_write_concurrent_increment() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    let threads = (0..8)
+        .map(|_| {
+            let table = table.clone();
+
+            std::thread::spawn(move || {
+                for _ in 0..25 {
+                    table
+                        .read_modify_write(ReadModifyWriteInput {
+                            row_key: "counter".into(),
+                            rules: vec![rule(
+                                "value:views",
+                                Operation::Increment(CellValue::I64(1)),
+                            )],
+                        })
+                        .unwrap();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    let result = table.read_modify_write(ReadModifyWriteInput {
+        row_key: "counter".into(),
+        rules: vec![rule("value:views", Operation::Increment(CellValue::I64(0)))],
+    })?;
+
+    // NOTE: No increments are lost
+    let row = &result.row;
+    assert_eq!(CellValue::I64(200), latest_value(row, "value", "views"));
+
+    Ok(())
+}
+
+#[test]
+pub fn read_modify_write_long_version_history() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = common::create_table(folder.path())?;
+
+    // NOTE: More versions than the default row cell limit
+    let mut writer = TableWriter::new(table.clone());
+    writer.write(&smoltable::row!(
+        "counter",
+        (0..70_000)
+            .map(|idx| smoltable::cell!("value:a", Some(idx), CellValue::I64(idx as i64)))
+            .chain(std::iter::once(smoltable::cell!(
+                "value:b",
+                Some(0),
+                CellValue::I64(5)
+            )))
+            .collect()
+    ))?;
+    writer.finalize()?;
+
+    let result = table.read_modify_write(ReadModifyWriteInput {
+        row_key: "counter".into(),
+        rules: vec![
+            rule("value:a", Operation::Increment(CellValue::I64(1))),
+            rule("value:b", Operation::Increment(CellValue::I64(1))),
+        ],
+    })?;
+
+    let row = &result.row;
+    assert_eq!(CellValue::I64(70_000), latest_value(row, "value", "a"));
+    assert_eq!(CellValue::I64(6), latest_value(row, "value", "b"));
+
+    Ok(())
+}
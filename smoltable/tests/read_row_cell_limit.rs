@@ -21,8 +21,10 @@ pub fn write_read_row_cell_limit() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -53,6 +55,7 @@ pub fn write_read_row_cell_limit() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: Some(7),
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
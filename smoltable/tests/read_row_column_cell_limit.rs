@@ -24,8 +24,10 @@ pub fn read_row_column_cell_limit() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -48,6 +50,7 @@ pub fn read_row_column_cell_limit() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -97,8 +100,10 @@ pub fn write_read_row_multiple_columns_cell_limit() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -127,11 +132,13 @@ pub fn write_read_row_multiple_columns_cell_limit() -> smoltable::Result<()> {
         column: Some(QueryRowInputColumnOptions {
             cell_limit: Some(2),
             filter: None,
+            ..Default::default()
         }),
         row: QueryRowInputRowOptions {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
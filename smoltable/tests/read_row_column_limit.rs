@@ -0,0 +1,201 @@
+mod common;
+
+use smoltable::{
+    query::{
+        row::{
+            ColumnOptions as QueryRowInputColumnOptions, Input as QueryRowInput,
+            RowOptions as QueryRowInputRowOptions,
+        },
+        scan::{
+            ColumnOptions as QueryPrefixColumnOptions, Input as QueryPrefixInput,
+            RowOptions as QueryPrefixRowOptions, ScanMode,
+        },
+    },
+    CellValue, ColumnKey, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+    common::create_column_family(&table, "another", Some(true))?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["a", "b"] {
+        for idx in 0..5 {
+            for ts in 0..3 {
+                writer.write(&smoltable::row!(
+                    row_key,
+                    vec![
+                        smoltable::cell!(
+                            format!("value:{idx}").as_str(),
+                            Some(ts),
+                            CellValue::I32(idx)
+                        ),
+                        smoltable::cell!(
+                            format!("another:{idx}").as_str(),
+                            Some(ts),
+                            CellValue::I32(idx)
+                        )
+                    ]
+                ))?;
+            }
+        }
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+#[test]
+pub fn read_row_column_limit_pagination() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let mut column_cursor: Option<ColumnKey> = None;
+    let mut visited_columns = vec![];
+    let mut page_count = 0;
+
+    loop {
+        let query_result = table.get_row(QueryRowInput {
+            column: Some(QueryRowInputColumnOptions {
+                cell_limit: None,
+                limit: Some(3),
+                start_after: column_cursor.clone(),
+                ..Default::default()
+            }),
+            row: QueryRowInputRowOptions {
+                key: "a".to_owned(),
+                cell_limit: None,
+            },
+            ..Default::default()
+        })?;
+
+        page_count += 1;
+
+        let row = query_result.row.unwrap();
+        assert!(row.column_count() <= 3);
+
+        for (family, columns) in row.columns {
+            for (qualifier, versions) in columns {
+                assert_eq!(3, versions.len());
+                visited_columns.push(format!("{family}:{qualifier}"));
+            }
+        }
+
+        column_cursor = query_result.column_cursor;
+
+        if column_cursor.is_none() {
+            break;
+        }
+    }
+
+    visited_columns.sort();
+
+    assert_eq!(4, page_count);
+    assert_eq!(
+        vec![
+            "another:0",
+            "another:1",
+            "another:2",
+            "another:3",
+            "another:4",
+            "value:0",
+            "value:1",
+            "value:2",
+            "value:3",
+            "value:4",
+        ],
+        visited_columns
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn scan_column_limit() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    // NOTE: The column cursor only applies to the first row
+    let mut column_cursor = Some(ColumnKey::try_from("another:3").unwrap());
+    let mut continuation = None;
+    let mut visited_columns = vec![];
+    let mut page_count = 0;
+
+    loop {
+        let query_result = table.scan(QueryPrefixInput {
+            column: Some(QueryPrefixColumnOptions {
+                cell_limit: Some(1),
+                limit: Some(2),
+                start_after: column_cursor.clone(),
+                ..Default::default()
+            }),
+            cell: None,
+            row: QueryPrefixRowOptions {
+                scan: ScanMode::Prefix(String::new()),
+                cell_limit: None,
+                offset: None,
+                limit: None,
+                sample: None,
+                continuation: continuation.clone(),
+            },
+            ..Default::default()
+        })?;
+
+        page_count += 1;
+
+        for row in query_result.rows {
+            assert!(row.column_count() <= 2);
+
+            let mut columns = vec![];
+
+            for (family, qualifiers) in row.columns {
+                for (qualifier, versions) in qualifiers {
+                    assert_eq!(1, versions.len());
+                    assert_eq!(2, versions[0].timestamp);
+                    columns.push(format!("{}/{family}:{qualifier}", row.row_key));
+                }
+            }
+
+            columns.sort();
+            visited_columns.extend(columns);
+        }
+
+        continuation = query_result.continuation;
+        column_cursor = query_result.column_cursor;
+
+        if continuation.is_none() {
+            assert!(column_cursor.is_none());
+            break;
+        }
+
+        assert!(column_cursor.is_some());
+    }
+
+    assert_eq!(7, page_count);
+    assert_eq!(
+        vec![
+            "a/another:4",
+            "a/value:0",
+            "a/value:1",
+            "a/value:2",
+            "a/value:3",
+            "a/value:4",
+            "b/another:0",
+            "b/another:1",
+            "b/another:2",
+            "b/another:3",
+            "b/another:4",
+            "b/value:0",
+            "b/value:1",
+            "b/value:2",
+            "b/value:3",
+            "b/value:4",
+        ],
+        visited_columns
+    );
+
+    Ok(())
+}
@@ -0,0 +1,294 @@
+use smoltable::{
+    query::row::{
+        ColumnOptions as QueryRowInputColumnOptions, Input as QueryRowInput,
+        RowOptions as QueryRowInputRowOptions,
+    },
+    CellValue, ColumnFamilyDefinition, ColumnFilter, ColumnRange, CreateColumnFamilyInput,
+    GarbageCollectionOptions, Smoltable, TableWriter,
+};
+use test_log::test;
+
+#[test]
+pub fn read_row_column_range() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![
+            ColumnFamilyDefinition {
+                name: "day".to_owned(),
+                gc_settings: GarbageCollectionOptions {
+                    ttl_secs: None,
+                    version_limit: None,
+                },
+                ..Default::default()
+            },
+            ColumnFamilyDefinition {
+                name: "dayz".to_owned(),
+                gc_settings: GarbageCollectionOptions {
+                    ttl_secs: None,
+                    version_limit: None,
+                },
+                ..Default::default()
+            },
+        ],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["test", "test2"] {
+        for day in ["20240101", "20240102", "20240103", "20240104", "20240105"] {
+            writer.write(&smoltable::row!(
+                row_key,
+                vec![
+                    smoltable::cell!(
+                        format!("day:{day}").as_str(),
+                        Some(0),
+                        CellValue::String(day.to_owned())
+                    ),
+                    smoltable::cell!(
+                        format!("dayz:{day}").as_str(),
+                        Some(0),
+                        CellValue::String(day.to_owned())
+                    )
+                ]
+            ))?;
+        }
+    }
+
+    writer.finalize()?;
+
+    let query_result = table.get_row(QueryRowInput {
+        column: Some(QueryRowInputColumnOptions {
+            cell_limit: None,
+            limit: None,
+            start_after: None,
+            filter: Some(ColumnFilter::Range(ColumnRange {
+                family: "day".into(),
+                start: "20240102".into(),
+                end: "20240104".into(),
+                inclusive: false,
+            })),
+        }),
+        row: QueryRowInputRowOptions {
+            key: "test".to_owned(),
+            cell_limit: None,
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(
+        serde_json::to_value(query_result.row).unwrap(),
+        serde_json::json!({
+            "row_key": "test",
+            "columns": {
+                "day": {
+                    "20240102": [
+                        { "time": 0, "type": "string", "value": "20240102" }
+                    ],
+                    "20240103": [
+                        { "time": 0, "type": "string", "value": "20240103" }
+                    ]
+                }
+            }
+        })
+    );
+
+    let query_result = table.get_row(QueryRowInput {
+        column: Some(QueryRowInputColumnOptions {
+            cell_limit: None,
+            limit: None,
+            start_after: None,
+            filter: Some(ColumnFilter::Range(ColumnRange {
+                family: "dayz".into(),
+                start: "20240104".into(),
+                end: "20240105".into(),
+                inclusive: true,
+            })),
+        }),
+        row: QueryRowInputRowOptions {
+            key: "test".to_owned(),
+            cell_limit: None,
+        },
+        ..Default::default()
+    })?;
+
+    let row = query_result.row.unwrap();
+    assert_eq!(1, row.family_count());
+    assert_eq!(2, row.cell_count());
+
+    Ok(())
+}
+
+#[test]
+pub fn read_row_column_range_multiple_locality_groups() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "another".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: Some(true),
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    writer.write(&smoltable::row!(
+        "a",
+        vec![smoltable::cell!("value:a", Some(0), CellValue::Byte(0))]
+    ))?;
+
+    writer.write(&smoltable::row!(
+        "b",
+        vec![
+            smoltable::cell!("another:a", Some(0), CellValue::Byte(1)),
+            smoltable::cell!("another:b", Some(0), CellValue::Byte(2)),
+            smoltable::cell!("another:c", Some(0), CellValue::Byte(3))
+        ]
+    ))?;
+
+    writer.finalize()?;
+
+    let query_result = table.get_row(QueryRowInput {
+        column: Some(QueryRowInputColumnOptions {
+            cell_limit: None,
+            limit: None,
+            start_after: None,
+            filter: Some(ColumnFilter::Range(ColumnRange {
+                family: "another".into(),
+                start: "b".into(),
+                end: "z".into(),
+                inclusive: false,
+            })),
+        }),
+        row: QueryRowInputRowOptions {
+            key: "b".to_owned(),
+            cell_limit: None,
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(query_result.affected_locality_groups, 1);
+
+    assert_eq!(
+        serde_json::to_value(query_result.row).unwrap(),
+        serde_json::json!({
+            "row_key": "b",
+            "columns": {
+                "another": {
+                    "b": [
+                        { "time": 0, "type": "byte", "value": 2 }
+                    ],
+                    "c": [
+                        { "time": 0, "type": "byte", "value": 3 }
+                    ]
+                }
+            }
+        })
+    );
+
+    // NOTE: The default locality group has no cells for the row,
+    // so the next locality group needs to be visited
+    let query_result = table.get_row(QueryRowInput {
+        column: None,
+        row: QueryRowInputRowOptions {
+            key: "b".to_owned(),
+            cell_limit: None,
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(query_result.affected_locality_groups, 2);
+    assert_eq!(3, query_result.row.unwrap().cell_count());
+
+    Ok(())
+}
+
+#[test]
+pub fn read_row_column_range_stops_at_end() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "day".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for idx in 0..1_000 {
+        writer.write(&smoltable::row!(
+            "test",
+            vec![smoltable::cell!(
+                format!("day:{idx:04}").as_str(),
+                Some(0),
+                CellValue::I32(idx)
+            )]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    for (inclusive, expected_cell_count) in [(false, 2), (true, 3)] {
+        let query_result = table.get_row(QueryRowInput {
+            column: Some(QueryRowInputColumnOptions {
+                cell_limit: None,
+                limit: None,
+                start_after: None,
+                filter: Some(ColumnFilter::Range(ColumnRange {
+                    family: "day".into(),
+                    start: "0010".into(),
+                    end: "0012".into(),
+                    inclusive,
+                })),
+            }),
+            row: QueryRowInputRowOptions {
+                key: "test".to_owned(),
+                cell_limit: None,
+            },
+            ..Default::default()
+        })?;
+
+        assert_eq!(expected_cell_count, query_result.row.unwrap().cell_count());
+
+        // NOTE: The columns after the end of the range are not read
+        assert!(query_result.cells_scanned_count < 100);
+    }
+
+    Ok(())
+}
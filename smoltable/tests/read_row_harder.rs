@@ -21,8 +21,10 @@ pub fn read_row_harder() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -62,6 +64,7 @@ pub fn read_row_harder() -> smoltable::Result<()> {
             key: "test2".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -25,6 +25,7 @@ pub fn read_row_multi_column_filter() -> smoltable::Result<()> {
                     ttl_secs: None,
                     version_limit: None,
                 },
+                ..Default::default()
             },
             ColumnFamilyDefinition {
                 name: "another".to_owned(),
@@ -32,6 +33,7 @@ pub fn read_row_multi_column_filter() -> smoltable::Result<()> {
                     ttl_secs: None,
                     version_limit: None,
                 },
+                ..Default::default()
             },
             ColumnFamilyDefinition {
                 name: "another_one".to_owned(),
@@ -39,9 +41,11 @@ pub fn read_row_multi_column_filter() -> smoltable::Result<()> {
                     ttl_secs: None,
                     version_limit: None,
                 },
+                ..Default::default()
             },
         ],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(3, table.list_column_families()?.len());
@@ -70,11 +74,13 @@ pub fn read_row_multi_column_filter() -> smoltable::Result<()> {
                 ColumnKey::try_from("another_one:").unwrap(),
             ])),
             cell_limit: None,
+            ..Default::default()
         }),
         row: QueryRowInputRowOptions {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -24,8 +24,10 @@ pub fn read_row_multi_column_filter_multiple_locality_groups() -> smoltable::Res
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
     table.create_column_families(&CreateColumnFamilyInput {
         column_families: vec![ColumnFamilyDefinition {
@@ -34,8 +36,10 @@ pub fn read_row_multi_column_filter_multiple_locality_groups() -> smoltable::Res
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: Some(true),
+        ..Default::default()
     })?;
     table.create_column_families(&CreateColumnFamilyInput {
         column_families: vec![ColumnFamilyDefinition {
@@ -44,8 +48,10 @@ pub fn read_row_multi_column_filter_multiple_locality_groups() -> smoltable::Res
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: Some(true),
+        ..Default::default()
     })?;
 
     assert_eq!(3, table.list_column_families()?.len());
@@ -75,11 +81,13 @@ pub fn read_row_multi_column_filter_multiple_locality_groups() -> smoltable::Res
             )
             .unwrap()])),
             cell_limit: None,
+            ..Default::default()
         }),
         row: QueryRowInputRowOptions {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -110,11 +118,13 @@ pub fn read_row_multi_column_filter_multiple_locality_groups() -> smoltable::Res
                 ColumnKey::try_from("another:").unwrap()
             ])),
             cell_limit: None,
+            ..Default::default()
         }),
         row: QueryRowInputRowOptions {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -22,6 +22,7 @@ pub fn read_row_multiple_families() -> smoltable::Result<()> {
                     ttl_secs: None,
                     version_limit: None,
                 },
+                ..Default::default()
             },
             ColumnFamilyDefinition {
                 name: "another".to_owned(),
@@ -29,9 +30,11 @@ pub fn read_row_multiple_families() -> smoltable::Result<()> {
                     ttl_secs: None,
                     version_limit: None,
                 },
+                ..Default::default()
             },
         ],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(2, table.list_column_families()?.len());
@@ -54,6 +57,7 @@ pub fn read_row_multiple_families() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
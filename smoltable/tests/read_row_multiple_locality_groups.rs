@@ -21,8 +21,10 @@ pub fn read_row_multiple_locality_groups() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
     table.create_column_families(&CreateColumnFamilyInput {
         column_families: vec![ColumnFamilyDefinition {
@@ -31,8 +33,10 @@ pub fn read_row_multiple_locality_groups() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: Some(true),
+        ..Default::default()
     })?;
 
     assert_eq!(2, table.list_column_families()?.len());
@@ -55,6 +59,7 @@ pub fn read_row_multiple_locality_groups() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 2);
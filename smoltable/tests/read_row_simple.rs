@@ -21,8 +21,10 @@ pub fn read_row_simple() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -46,6 +48,7 @@ pub fn read_row_simple() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -88,8 +91,10 @@ pub fn read_row_simple_multiple_columns() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -112,6 +117,7 @@ pub fn read_row_simple_multiple_columns() -> smoltable::Result<()> {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.cells_scanned_count, 2);
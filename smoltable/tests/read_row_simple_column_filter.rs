@@ -25,6 +25,7 @@ pub fn read_row_simple_column_filter() -> smoltable::Result<()> {
                     ttl_secs: None,
                     version_limit: None,
                 },
+                ..Default::default()
             },
             ColumnFamilyDefinition {
                 name: "another".to_owned(),
@@ -32,9 +33,11 @@ pub fn read_row_simple_column_filter() -> smoltable::Result<()> {
                     ttl_secs: None,
                     version_limit: None,
                 },
+                ..Default::default()
             },
         ],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(2, table.list_column_families()?.len());
@@ -55,11 +58,13 @@ pub fn read_row_simple_column_filter() -> smoltable::Result<()> {
         column: Some(QueryRowInputColumnOptions {
             filter: Some(ColumnFilter::Key(ColumnKey::try_from("value:").unwrap())),
             cell_limit: None,
+            ..Default::default()
         }),
         row: QueryRowInputRowOptions {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -24,8 +24,10 @@ pub fn read_row_simple_column_filter_multiple_locality_groups() -> smoltable::Re
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
     table.create_column_families(&CreateColumnFamilyInput {
         column_families: vec![ColumnFamilyDefinition {
@@ -34,8 +36,10 @@ pub fn read_row_simple_column_filter_multiple_locality_groups() -> smoltable::Re
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: Some(true),
+        ..Default::default()
     })?;
 
     assert_eq!(2, table.list_column_families()?.len());
@@ -56,11 +60,13 @@ pub fn read_row_simple_column_filter_multiple_locality_groups() -> smoltable::Re
         column: Some(QueryRowInputColumnOptions {
             filter: Some(ColumnFilter::Key(ColumnKey::try_from("value:").unwrap())),
             cell_limit: None,
+            ..Default::default()
         }),
         row: QueryRowInputRowOptions {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -89,11 +95,13 @@ pub fn read_row_simple_column_filter_multiple_locality_groups() -> smoltable::Re
         column: Some(QueryRowInputColumnOptions {
             filter: Some(ColumnFilter::Key(ColumnKey::try_from("another:").unwrap())),
             cell_limit: None,
+            ..Default::default()
         }),
         row: QueryRowInputRowOptions {
             key: "test".to_owned(),
             cell_limit: None,
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
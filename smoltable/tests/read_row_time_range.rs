@@ -0,0 +1,119 @@
+use smoltable::{
+    query::{
+        row::{
+            CellOptions as QueryRowInputCellOptions, Input as QueryRowInput,
+            RowOptions as QueryRowInputRowOptions,
+        },
+        scan::TimeRange,
+    },
+    CellValue, ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions,
+    Smoltable, TableWriter,
+};
+use test_log::test;
+
+#[test]
+pub fn read_row_time_range() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for ts in 0..10 {
+        writer.write(&smoltable::row!(
+            "test",
+            vec![
+                smoltable::cell!("value:a", Some(ts), CellValue::I32(ts as i32)),
+                smoltable::cell!("value:b", Some(ts), CellValue::I32(ts as i32))
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    let query_result = table.get_row(QueryRowInput {
+        column: None,
+        row: QueryRowInputRowOptions {
+            key: "test".to_owned(),
+            cell_limit: None,
+        },
+        cell: Some(QueryRowInputCellOptions {
+            time: Some(TimeRange {
+                start: Some(3),
+                end: Some(6),
+            }),
+        }),
+        ..Default::default()
+    })?;
+
+    assert_eq!(
+        serde_json::to_value(query_result.row).unwrap(),
+        serde_json::json!({
+            "row_key": "test",
+            "columns": {
+                "value": {
+                    "a": [
+                        { "time": 5, "type": "i32", "value": 5 },
+                        { "time": 4, "type": "i32", "value": 4 },
+                        { "time": 3, "type": "i32", "value": 3 }
+                    ],
+                    "b": [
+                        { "time": 5, "type": "i32", "value": 5 },
+                        { "time": 4, "type": "i32", "value": 4 },
+                        { "time": 3, "type": "i32", "value": 3 }
+                    ]
+                }
+            }
+        })
+    );
+
+    let query_result = table.get_row(QueryRowInput {
+        column: None,
+        row: QueryRowInputRowOptions {
+            key: "test".to_owned(),
+            cell_limit: None,
+        },
+        cell: Some(QueryRowInputCellOptions {
+            time: Some(TimeRange {
+                start: Some(8),
+                end: None,
+            }),
+        }),
+        ..Default::default()
+    })?;
+
+    assert_eq!(4, query_result.row.unwrap().cell_count());
+
+    let query_result = table.get_row(QueryRowInput {
+        column: None,
+        row: QueryRowInputRowOptions {
+            key: "test".to_owned(),
+            cell_limit: None,
+        },
+        cell: Some(QueryRowInputCellOptions {
+            time: Some(TimeRange {
+                start: None,
+                end: Some(0),
+            }),
+        }),
+        ..Default::default()
+    })?;
+
+    assert!(query_result.row.is_none());
+
+    Ok(())
+}
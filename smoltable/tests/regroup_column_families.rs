@@ -0,0 +1,240 @@
+mod common;
+
+use smoltable::{
+    query::{
+        row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+        scan::{Input as QueryPrefixInput, RowOptions as QueryPrefixRowOptions, ScanMode},
+    },
+    CellValue, LocalityGroupTarget, RegroupColumnFamiliesInput, Smoltable, TableWriter,
+};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use test_log::test;
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+    common::create_column_family(&table, "another", None)?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["a", "b"] {
+        writer.write(&smoltable::row!(
+            row_key,
+            vec![
+                smoltable::cell!("value:asd", Some(0), CellValue::I32(0)),
+                smoltable::cell!("another:asd", Some(0), CellValue::I32(1)),
+                smoltable::cell!("another:def", Some(0), CellValue::I32(2))
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+fn scan_cell_count(table: &Smoltable) -> smoltable::Result<usize> {
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix(String::new()),
+            cell_limit: None,
+            offset: None,
+            limit: None,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+
+    Ok(query_result.rows.iter().map(|row| row.cell_count()).sum())
+}
+
+#[test]
+pub fn regroup_column_families() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    {
+        let table = create_table(folder.path())?;
+        assert_eq!(6, table.tree.len()?);
+
+        let moved_cells_count = table.regroup_column_families(&RegroupColumnFamiliesInput {
+            column_families: vec!["another".to_owned()],
+            target: LocalityGroupTarget::New,
+        })?;
+        assert_eq!(4, moved_cells_count);
+
+        assert_eq!(2, table.tree.len()?);
+
+        let locality_groups = table.locality_groups.read().unwrap();
+        assert_eq!(1, locality_groups.len());
+        assert!(locality_groups[0].contains_column_family("another"));
+        assert_eq!(4, locality_groups[0].tree.len()?);
+    }
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace)?;
+    assert_eq!(1, table.locality_groups.read().unwrap().len());
+    assert_eq!(6, scan_cell_count(&table)?);
+
+    // NOTE: New writes go into the new locality group
+    TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "c",
+            vec![smoltable::cell!("another:asd", Some(0), CellValue::I32(1))]
+        )],
+    )?;
+    assert_eq!(2, table.tree.len()?);
+
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: "a".to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+    assert_eq!(3, query_result.row.unwrap().cell_count());
+
+    // NOTE: Merge the locality group back into the default locality group
+    table.regroup_column_families(&RegroupColumnFamiliesInput {
+        column_families: vec!["another".to_owned()],
+        target: LocalityGroupTarget::Default,
+    })?;
+
+    assert!(table.locality_groups.read().unwrap().is_empty());
+    assert_eq!(7, table.tree.len()?);
+    assert_eq!(7, scan_cell_count(&table)?);
+
+    Ok(())
+}
+
+#[test]
+pub fn regroup_column_families_not_found() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    assert!(matches!(
+        table.regroup_column_families(&RegroupColumnFamiliesInput {
+            column_families: vec!["missing".to_owned()],
+            target: LocalityGroupTarget::New,
+        }),
+        Err(smoltable::Error::ColumnFamilyNotFound(_))
+    ));
+
+    assert!(matches!(
+        table.regroup_column_families(&RegroupColumnFamiliesInput {
+            column_families: vec!["value".to_owned()],
+            target: LocalityGroupTarget::Existing("missing".to_owned()),
+        }),
+        Err(smoltable::Error::LocalityGroupNotFound(_))
+    ));
+
+    assert_eq!(6, scan_cell_count(&table)?);
+
+    Ok(())
+}
+
+#[test]
+pub fn regroup_column_families_duplicate_names() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let moved_cells_count = table.regroup_column_families(&RegroupColumnFamiliesInput {
+        column_families: vec!["another".to_owned(), "another".to_owned()],
+        target: LocalityGroupTarget::New,
+    })?;
+    assert_eq!(4, moved_cells_count);
+
+    assert_eq!(2, table.tree.len()?);
+
+    let locality_groups = table.locality_groups.read().unwrap();
+    assert_eq!(1, locality_groups.len());
+    assert_eq!(
+        vec!["another"],
+        locality_groups[0]
+            .column_families
+            .iter()
+            .map(|name| &**name)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(4, locality_groups[0].tree.len()?);
+
+    Ok(())
+}
+
+#[test]
+pub fn regroup_column_families_concurrent_writes() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let is_done = Arc::new(AtomicBool::new(false));
+
+    let writer_thread = {
+        let table = table.clone();
+        let is_done = is_done.clone();
+
+        std::thread::spawn(move || -> smoltable::Result<()> {
+            for idx in 0..1_000 {
+                let row_key = format!("w{idx:04}");
+
+                TableWriter::write_batch(
+                    table.clone(),
+                    &[smoltable::row!(
+                        &row_key,
+                        vec![smoltable::cell!(
+                            "another:asd",
+                            Some(0),
+                            CellValue::I32(idx)
+                        )]
+                    )],
+                )?;
+
+                if idx % 2 == 0 {
+                    table.delete_row(row_key, None)?;
+                }
+            }
+
+            is_done.store(true, Ordering::Release);
+
+            Ok(())
+        })
+    };
+
+    let mut regroup_count = 0;
+
+    while regroup_count < 2 || !is_done.load(Ordering::Acquire) {
+        let target = if regroup_count % 2 == 0 {
+            LocalityGroupTarget::New
+        } else {
+            LocalityGroupTarget::Default
+        };
+
+        table.regroup_column_families(&RegroupColumnFamiliesInput {
+            column_families: vec!["another".to_owned()],
+            target,
+        })?;
+
+        regroup_count += 1;
+    }
+
+    writer_thread.join().expect("should join")?;
+
+    // NOTE: Deleted cells did not reappear, and no write was lost
+    assert_eq!(6 + 500, scan_cell_count(&table)?);
+
+    // NOTE: No copies are left behind in the old locality groups
+    let mut stored_cells_count = table.tree.len()?;
+
+    for lg in table.locality_groups.read().unwrap().iter() {
+        stored_cells_count += lg.tree.len()?;
+    }
+
+    assert_eq!(6 + 500, stored_cells_count);
+
+    Ok(())
+}
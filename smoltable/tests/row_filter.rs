@@ -0,0 +1,273 @@
+mod common;
+
+use smoltable::{
+    query::{
+        check_and_mutate::CompareOp,
+        count::{Input as CountInput, RowOptions as CountRowOptions},
+        delete::{DeleteMode, Input as DeleteInput, RowOptions as DeleteRowOptions},
+        filter::{Pattern, RowFilter, ValueComparison, ValueRange},
+        row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+        scan::{Input as QueryPrefixInput, RowOptions as QueryPrefixRowOptions, ScanMode},
+    },
+    CellValue, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for (row_key, age) in [
+        ("user:1", 20),
+        ("user:2", 35),
+        ("user:x", 50),
+        ("admin:1", 40),
+    ] {
+        writer.write(&smoltable::row!(
+            row_key,
+            vec![
+                smoltable::cell!("value:name", Some(0), CellValue::String(row_key.into())),
+                smoltable::cell!("value:age", Some(0), CellValue::I32(age - 1)),
+                smoltable::cell!("value:age", Some(1), CellValue::I32(age)),
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+fn scan(table: &Smoltable, filter: RowFilter) -> smoltable::Result<Vec<(String, usize)>> {
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix(String::new()),
+            cell_limit: None,
+            offset: None,
+            limit: None,
+            sample: None,
+            ..Default::default()
+        },
+        filter: Some(filter),
+    })?;
+
+    Ok(query_result
+        .rows
+        .into_iter()
+        .map(|row| (row.row_key.clone(), row.cell_count()))
+        .collect())
+}
+
+#[test]
+pub fn row_filter_scan() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    assert_eq!(
+        vec![("user:1".to_owned(), 3), ("user:2".to_owned(), 3)],
+        scan(&table, RowFilter::RowKeyRegex(Pattern::new("user:[0-9]+")?))?
+    );
+
+    // Latest age of users that are at least 35
+    assert_eq!(
+        vec![("user:2".to_owned(), 1), ("user:x".to_owned(), 1)],
+        scan(
+            &table,
+            RowFilter::Chain(vec![
+                RowFilter::RowKeyRegex(Pattern::new("user:.*")?),
+                RowFilter::QualifierRegex(Pattern::new("age")?),
+                RowFilter::Latest(1),
+                RowFilter::Value(ValueComparison {
+                    op: CompareOp::GreaterOrEqual,
+                    value: CellValue::I32(35),
+                }),
+            ])
+        )?
+    );
+
+    // Older versions, or names
+    assert_eq!(
+        vec![
+            ("admin:1".to_owned(), 2),
+            ("user:1".to_owned(), 2),
+            ("user:2".to_owned(), 2),
+            ("user:x".to_owned(), 2),
+        ],
+        scan(
+            &table,
+            RowFilter::Interleave(vec![
+                RowFilter::Not(Box::new(RowFilter::Latest(1))),
+                RowFilter::QualifierRegex(Pattern::new("name")?),
+            ])
+        )?
+    );
+
+    assert_eq!(
+        vec![("admin:1".to_owned(), 2), ("user:2".to_owned(), 2)],
+        scan(
+            &table,
+            RowFilter::ValueRange(ValueRange {
+                start: Some(CellValue::I32(34)),
+                end: Some(CellValue::I32(49)),
+            })
+        )?
+    );
+
+    let count_result = table.scan_count(CountInput {
+        column: None,
+        cell: None,
+        row: CountRowOptions {
+            scan: ScanMode::Prefix("user".into()),
+        },
+        filter: Some(RowFilter::Latest(1)),
+    })?;
+
+    assert_eq!(3, count_result.row_count);
+    assert_eq!(6, count_result.cell_count);
+
+    Ok(())
+}
+
+#[test]
+pub fn row_filter_get_row() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: "user:1".to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        cell: None,
+        filter: Some(RowFilter::Chain(vec![
+            RowFilter::QualifierRegex(Pattern::new("age")?),
+            RowFilter::Not(Box::new(RowFilter::Latest(1))),
+        ])),
+    })?;
+
+    let row = query_result.row.expect("row should exist");
+    assert_eq!(1, row.cell_count());
+    assert_eq!(
+        CellValue::I32(19),
+        row.columns["value"]["age"].first().unwrap().value
+    );
+
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: "user:1".to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        cell: None,
+        filter: Some(RowFilter::RowKeyRegex(Pattern::new("admin:.*")?)),
+    })?;
+
+    assert!(query_result.row.is_none());
+
+    Ok(())
+}
+
+#[test]
+pub fn row_filter_delete() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    // Remove all old versions
+    let deleted_count = table.delete_rows(DeleteInput {
+        row: DeleteRowOptions {
+            mode: DeleteMode::Prefix(String::new()),
+            cell_limit: None,
+            cursor: None,
+        },
+        column_filter: None,
+        filter: Some(RowFilter::Not(Box::new(RowFilter::Latest(1)))),
+    })?;
+    assert_eq!(4, deleted_count.deleted_cells_count);
+
+    let deleted_count = table.delete_row_filtered(
+        "user:x".to_owned(),
+        None,
+        Some(RowFilter::Value(ValueComparison {
+            op: CompareOp::Equal,
+            value: CellValue::I32(50),
+        })),
+    )?;
+    assert_eq!(1, deleted_count);
+
+    assert_eq!(
+        vec![
+            ("admin:1".to_owned(), 2),
+            ("user:1".to_owned(), 2),
+            ("user:2".to_owned(), 2),
+            ("user:x".to_owned(), 1),
+        ],
+        scan(&table, RowFilter::Chain(vec![]))?
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn row_filter_deserialize() -> smoltable::Result<()> {
+    let filter: RowFilter = serde_json::from_str(
+        r#"{
+            "chain": [
+                { "row_key_regex": "user:.*" },
+                { "not": { "qualifier_regex": "name" } },
+                { "latest": 1 },
+                { "value": { "op": "gt", "type": "i32", "value": 30 } },
+                { "value_range": { "end": { "type": "i32", "value": 40 } } }
+            ]
+        }"#,
+    )
+    .expect("should deserialize");
+
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    assert_eq!(vec![("user:2".to_owned(), 1)], scan(&table, filter)?);
+
+    assert!(serde_json::from_str::<RowFilter>(r#"{ "row_key_regex": "user:(" }"#).is_err());
+
+    Ok(())
+}
+
+#[test]
+pub fn row_filter_optional() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    // NOTE: Inputs without a filter stay valid
+    let input: QueryPrefixInput =
+        serde_json::from_str(r#"{ "row": { "prefix": "user:" } }"#).expect("should deserialize");
+    assert!(input.filter.is_none());
+    assert_eq!(3, table.scan(input)?.rows.len());
+
+    let input: QueryRowInput =
+        serde_json::from_str(r#"{ "row": { "key": "user:1" } }"#).expect("should deserialize");
+    assert!(input.filter.is_none());
+
+    let query_result = table.scan(QueryPrefixInput {
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix("admin:".to_owned()),
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+    assert_eq!(1, query_result.rows.len());
+
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: "user:1".to_owned(),
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+    assert!(query_result.row.is_some());
+
+    Ok(())
+}
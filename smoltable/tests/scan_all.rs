@@ -21,8 +21,10 @@ pub fn scan_all() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -73,7 +75,9 @@ pub fn scan_all() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -0,0 +1,251 @@
+mod common;
+
+use smoltable::{
+    query::{
+        continuation::ContinuationToken,
+        scan::{
+            CellOptions as QueryPrefixCellOptions, Input as QueryPrefixInput, Range,
+            RowOptions as QueryPrefixRowOptions, ScanMode,
+        },
+    },
+    CellValue, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for idx in 0..10 {
+        writer.write(&smoltable::row!(
+            &format!("row{idx}"),
+            vec![
+                smoltable::cell!("value:a", Some(0), CellValue::I32(idx)),
+                smoltable::cell!("value:b", Some(0), CellValue::I32(idx))
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+fn scan_all_pages(table: &Smoltable, scan: ScanMode) -> smoltable::Result<(Vec<String>, usize)> {
+    let mut continuation: Option<ContinuationToken> = None;
+    let mut row_keys = vec![];
+    let mut page_count = 0;
+
+    loop {
+        let query_result = table.scan(QueryPrefixInput {
+            column: None,
+            cell: None,
+            row: QueryPrefixRowOptions {
+                scan: scan.clone(),
+                cell_limit: None,
+                offset: None,
+                limit: Some(3),
+                sample: None,
+                continuation: continuation.clone(),
+            },
+            ..Default::default()
+        })?;
+
+        page_count += 1;
+
+        assert!(query_result.rows.len() <= 3);
+        row_keys.extend(query_result.rows.into_iter().map(|row| row.row_key));
+
+        continuation = query_result.continuation;
+
+        if continuation.is_none() {
+            break;
+        }
+
+        // NOTE: Serialize the token, as a client would
+        let token = serde_json::to_string(&continuation).unwrap();
+        continuation = serde_json::from_str(&token).unwrap();
+    }
+
+    Ok((row_keys, page_count))
+}
+
+#[test]
+pub fn scan_continuation_prefix() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let (row_keys, page_count) = scan_all_pages(&table, ScanMode::Prefix("row".into()))?;
+
+    assert_eq!(4, page_count);
+    assert_eq!(
+        (0..10).map(|idx| format!("row{idx}")).collect::<Vec<_>>(),
+        row_keys
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn scan_continuation_range() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let (row_keys, page_count) = scan_all_pages(
+        &table,
+        ScanMode::Range(Range {
+            start: "row2".into(),
+            end: "row8".into(),
+            inclusive: false,
+        }),
+    )?;
+
+    assert_eq!(2, page_count);
+    assert_eq!(
+        (2..8).map(|idx| format!("row{idx}")).collect::<Vec<_>>(),
+        row_keys
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn scan_continuation_latest_state() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix("row".into()),
+            cell_limit: None,
+            offset: None,
+            limit: Some(5),
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(5, query_result.rows.len());
+    assert!(query_result.continuation.is_some());
+
+    // NOTE: Pages are read at the latest state, so rows written
+    // after the first page are visible to the next page
+    let mut writer = TableWriter::new(table.clone());
+    writer.write(&smoltable::row!(
+        "row5a",
+        vec![smoltable::cell!("value:a", Some(0), CellValue::I32(0))]
+    ))?;
+    writer.finalize()?;
+
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix("row".into()),
+            cell_limit: None,
+            offset: None,
+            limit: Some(5),
+            sample: None,
+            continuation: query_result.continuation,
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(
+        vec!["row5", "row5a", "row6", "row7", "row8"],
+        query_result
+            .rows
+            .into_iter()
+            .map(|row| row.row_key)
+            .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn scan_continuation_wide_row() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for idx in 0..10 {
+        for ts in 0..2 {
+            writer.write(&smoltable::row!(
+                "row4",
+                vec![smoltable::cell!(
+                    format!("value:wide{idx}").as_str(),
+                    Some(ts),
+                    CellValue::I32(idx)
+                )]
+            ))?;
+        }
+    }
+
+    writer.finalize()?;
+
+    let mut continuation: Option<ContinuationToken> = None;
+    let mut cells = vec![];
+    let mut page_count = 0;
+
+    loop {
+        let query_result = table.scan(QueryPrefixInput {
+            column: None,
+            cell: Some(QueryPrefixCellOptions {
+                limit: Some(7),
+                time: None,
+            }),
+            row: QueryPrefixRowOptions {
+                scan: ScanMode::Prefix("row".into()),
+                cell_limit: None,
+                offset: None,
+                limit: None,
+                sample: None,
+                continuation: continuation.clone(),
+            },
+            ..Default::default()
+        })?;
+
+        page_count += 1;
+
+        for row in query_result.rows {
+            for (family, columns) in row.columns {
+                for (qualifier, versions) in columns {
+                    for cell in versions {
+                        cells.push(format!(
+                            "{}/{family}:{qualifier}@{}",
+                            row.row_key, cell.timestamp
+                        ));
+                    }
+                }
+            }
+        }
+
+        continuation = query_result.continuation;
+
+        if continuation.is_none() {
+            break;
+        }
+    }
+
+    // NOTE: 20 narrow cells + 20 versions in the wide row
+    assert_eq!(6, page_count);
+    assert_eq!(40, cells.len());
+
+    cells.sort();
+    cells.dedup();
+    assert_eq!(40, cells.len());
+
+    for idx in 0..10 {
+        for ts in 0..2 {
+            assert!(cells.contains(&format!("row4/value:wide{idx}@{ts}")));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use smoltable::{
+    query::scan::{Input as QueryPrefixInput, RowOptions as QueryPrefixRowOptions, ScanMode},
+    CellValue, ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions,
+    Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn scan_input(limit: Option<u32>) -> QueryPrefixInput {
+    QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix("row".into()),
+            cell_limit: None,
+            offset: Some(1),
+            limit,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+pub fn scan_each() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for idx in 0..10 {
+        writer.write(&smoltable::row!(
+            &format!("row{idx}"),
+            vec![
+                smoltable::cell!("value:a", Some(0), CellValue::I32(idx)),
+                smoltable::cell!("value:b", Some(0), CellValue::I32(idx))
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    // Same result as a collected scan
+    let mut row_keys = vec![];
+
+    let stats = table.scan_each(scan_input(Some(4)), |row| {
+        assert_eq!(2, row.cell_count());
+        row_keys.push(row.row_key);
+        true
+    })?;
+
+    let query_result = table.scan(scan_input(Some(4)))?;
+
+    assert_eq!(vec!["row1", "row2", "row3", "row4"], row_keys);
+    assert_eq!(
+        row_keys,
+        query_result
+            .rows
+            .into_iter()
+            .map(|row| row.row_key)
+            .collect::<Vec<_>>()
+    );
+    assert!(stats.continuation.is_some());
+    assert_eq!(
+        serde_json::to_string(&query_result.continuation).unwrap(),
+        serde_json::to_string(&stats.continuation).unwrap()
+    );
+    assert_eq!(query_result.rows_scanned_count, stats.rows_scanned_count);
+
+    // Stop early
+    let mut row_keys = vec![];
+
+    let stats = table.scan_each(scan_input(None), |row| {
+        row_keys.push(row.row_key);
+        row_keys.len() < 2
+    })?;
+
+    assert_eq!(vec!["row1", "row2"], row_keys);
+    assert!(stats.continuation.is_none());
+
+    Ok(())
+}
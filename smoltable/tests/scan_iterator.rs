@@ -0,0 +1,152 @@
+mod common;
+
+use smoltable::{
+    query::{
+        count::{Input as CountInput, RowOptions as CountRowOptions},
+        filter::RowFilter,
+        scan::{Input as QueryPrefixInput, RowOptions as QueryPrefixRowOptions, ScanMode},
+    },
+    CellValue, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn scan_input(limit: Option<u32>) -> QueryPrefixInput {
+    QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix("row".into()),
+            cell_limit: None,
+            offset: None,
+            limit,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for idx in 0..10 {
+        writer.write(&smoltable::row!(
+            &format!("row{idx}"),
+            vec![
+                smoltable::cell!("value:a", Some(0), CellValue::I32(idx)),
+                smoltable::cell!("value:a", Some(1), CellValue::I32(idx)),
+                smoltable::cell!("value:b", Some(0), CellValue::I32(idx))
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+#[test]
+pub fn scan_iterator_rows() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    // Same result as a collected scan
+    let mut rows = table.scan_rows(scan_input(Some(4)))?;
+    let row_keys = (&mut rows)
+        .map(|row| row.map(|row| row.row_key))
+        .collect::<smoltable::Result<Vec<_>>>()?;
+    let stats = rows.stats();
+
+    let query_result = table.scan(scan_input(Some(4)))?;
+
+    assert_eq!(vec!["row0", "row1", "row2", "row3"], row_keys);
+    assert_eq!(
+        row_keys,
+        query_result
+            .rows
+            .into_iter()
+            .map(|row| row.row_key)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        serde_json::to_string(&query_result.continuation).unwrap(),
+        serde_json::to_string(&stats.continuation).unwrap()
+    );
+    assert_eq!(query_result.rows_scanned_count, stats.rows_scanned_count);
+
+    // Continue with the next page
+    let mut input = scan_input(None);
+    input.row.continuation = stats.continuation;
+
+    let row_keys = table
+        .scan_rows(input)?
+        .map(|row| row.map(|row| row.row_key))
+        .collect::<smoltable::Result<Vec<_>>>()?;
+
+    assert_eq!(
+        vec!["row4", "row5", "row6", "row7", "row8", "row9"],
+        row_keys
+    );
+
+    // Stop early
+    let mut rows = table.scan_rows(scan_input(None))?;
+
+    let row = rows.next().expect("row should exist")?;
+    assert_eq!("row0", row.row_key);
+    assert_eq!(3, row.cell_count());
+
+    let row = rows.next().expect("row should exist")?;
+    assert_eq!("row1", row.row_key);
+
+    let stats = rows.stats();
+    assert!(stats.continuation.is_none());
+    assert!(stats.rows_scanned_count < 10);
+
+    Ok(())
+}
+
+#[test]
+pub fn scan_iterator_cells() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    let cells = table
+        .scan_cells(CountInput {
+            column: None,
+            cell: None,
+            row: CountRowOptions {
+                scan: ScanMode::Prefix("row1".into()),
+            },
+            ..Default::default()
+        })?
+        .collect::<smoltable::Result<Vec<_>>>()?;
+
+    assert_eq!(
+        vec![
+            ("value:a".to_owned(), 1),
+            ("value:a".to_owned(), 0),
+            ("value:b".to_owned(), 0),
+        ],
+        cells
+            .iter()
+            .map(|cell| (cell.column_key.to_string(), cell.timestamp))
+            .collect::<Vec<_>>()
+    );
+
+    let mut cells = table.scan_cells(CountInput {
+        column: None,
+        cell: None,
+        row: CountRowOptions {
+            scan: ScanMode::Prefix("row".into()),
+        },
+        filter: Some(RowFilter::Latest(1)),
+    })?;
+
+    assert_eq!(20, (&mut cells).count());
+    assert_eq!(1, cells.affected_locality_groups());
+    assert_eq!(30, cells.cells_scanned_count());
+
+    Ok(())
+}
@@ -21,8 +21,10 @@ pub fn scan_prefix_simple() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -76,7 +78,9 @@ pub fn scan_prefix_simple() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -111,7 +115,9 @@ pub fn scan_prefix_simple() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.cells_scanned_count, 3);
@@ -169,8 +175,10 @@ pub fn scan_prefix_simple_multiple_columns() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -221,7 +229,9 @@ pub fn scan_prefix_simple_multiple_columns() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.cells_scanned_count, 7);
@@ -262,7 +272,9 @@ pub fn scan_prefix_simple_multiple_columns() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.cells_scanned_count, 5);
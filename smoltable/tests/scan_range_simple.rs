@@ -21,8 +21,10 @@ pub fn scan_range_simple() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -80,7 +82,9 @@ pub fn scan_range_simple() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.affected_locality_groups, 1);
@@ -139,8 +143,10 @@ pub fn scan_range_simple_exclusive() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -198,7 +204,9 @@ pub fn scan_range_simple_exclusive() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.cells_scanned_count, 2);
@@ -242,8 +250,10 @@ pub fn scan_range_simple_multi_columns() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -300,7 +310,9 @@ pub fn scan_range_simple_multi_columns() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.cells_scanned_count, 3);
@@ -365,8 +377,10 @@ pub fn scan_range_simple_exclusive_multi_columns() -> smoltable::Result<()> {
                 ttl_secs: None,
                 version_limit: None,
             },
+            ..Default::default()
         }],
         locality_group: None,
+        ..Default::default()
     })?;
 
     assert_eq!(1, table.list_column_families()?.len());
@@ -423,7 +437,9 @@ pub fn scan_range_simple_exclusive_multi_columns() -> smoltable::Result<()> {
             offset: None,
             limit: None,
             sample: None,
+            ..Default::default()
         },
+        ..Default::default()
     })?;
 
     assert_eq!(query_result.cells_scanned_count, 3);
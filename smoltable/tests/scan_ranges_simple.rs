@@ -0,0 +1,192 @@
+mod common;
+
+use smoltable::{
+    query::{
+        count::{Input as CountInput, RowOptions as CountRowOptions},
+        scan::{Input as QueryPrefixInput, Range, RowOptions as QueryPrefixRowOptions, ScanMode},
+    },
+    CellValue, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn create_table(
+    folder: &std::path::Path,
+    locality_group: Option<bool>,
+) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+    common::create_column_family(&table, "another", locality_group)?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["a", "b", "ba", "c", "d", "e"] {
+        writer.write(&smoltable::row!(
+            row_key,
+            vec![
+                smoltable::cell!("value:", Some(0), CellValue::String(row_key.to_owned())),
+                smoltable::cell!("another:", Some(0), CellValue::Byte(0))
+            ]
+        ))?;
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+fn row_keys(rows: &[smoltable::Row]) -> Vec<&str> {
+    rows.iter().map(|row| row.row_key.as_str()).collect()
+}
+
+#[test]
+pub fn scan_ranges_simple() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path(), None)?;
+
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Ranges(vec![
+                Range {
+                    start: "d".into(),
+                    end: "e".into(),
+                    inclusive: false,
+                },
+                Range {
+                    start: "b".into(),
+                    end: "ba".into(),
+                    inclusive: true,
+                },
+            ]),
+            cell_limit: None,
+            offset: None,
+            limit: None,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(query_result.affected_locality_groups, 1);
+    assert_eq!(vec!["b", "ba", "d"], row_keys(&query_result.rows));
+    assert_eq!(
+        6,
+        query_result
+            .rows
+            .iter()
+            .map(|x| x.cell_count())
+            .sum::<usize>()
+    );
+
+    let count_result = table.scan_count(CountInput {
+        column: None,
+        cell: None,
+        row: CountRowOptions {
+            scan: ScanMode::Ranges(vec![
+                Range {
+                    start: "b".into(),
+                    end: "ba".into(),
+                    inclusive: true,
+                },
+                Range {
+                    start: "d".into(),
+                    end: "e".into(),
+                    inclusive: false,
+                },
+            ]),
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(3, count_result.row_count);
+    assert_eq!(6, count_result.cell_count);
+
+    Ok(())
+}
+
+#[test]
+pub fn scan_ranges_overlapping() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path(), None)?;
+
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Ranges(vec![
+                Range {
+                    start: "a".into(),
+                    end: "c".into(),
+                    inclusive: true,
+                },
+                Range {
+                    start: "b".into(),
+                    end: "d".into(),
+                    inclusive: true,
+                },
+            ]),
+            cell_limit: None,
+            offset: None,
+            limit: None,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(vec!["a", "b", "ba", "c", "d"], row_keys(&query_result.rows));
+    assert_eq!(
+        10,
+        query_result
+            .rows
+            .iter()
+            .map(|x| x.cell_count())
+            .sum::<usize>()
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn scan_ranges_offset_limit_multiple_locality_groups() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path(), Some(true))?;
+
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: None,
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Ranges(vec![
+                Range {
+                    start: "a".into(),
+                    end: "b".into(),
+                    inclusive: true,
+                },
+                Range {
+                    start: "c".into(),
+                    end: "z".into(),
+                    inclusive: false,
+                },
+            ]),
+            cell_limit: None,
+            offset: Some(1),
+            limit: Some(2),
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(query_result.affected_locality_groups, 2);
+    assert_eq!(vec!["b", "c"], row_keys(&query_result.rows));
+    assert_eq!(
+        4,
+        query_result
+            .rows
+            .iter()
+            .map(|x| x.cell_count())
+            .sum::<usize>()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use smoltable::{
+    query::{
+        count::{
+            CellOptions as CountCellOptions, Input as CountInput, RowOptions as CountRowOptions,
+        },
+        scan::{
+            CellOptions as QueryPrefixCellOptions, Input as QueryPrefixInput,
+            RowOptions as QueryPrefixRowOptions, ScanMode, TimeRange,
+        },
+    },
+    CellValue, ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions,
+    Smoltable, TableWriter,
+};
+use test_log::test;
+
+#[test]
+pub fn scan_time_range() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "another".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: Some(true),
+        ..Default::default()
+    })?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["a", "b", "c"] {
+        for ts in 0..100 {
+            writer.write(&smoltable::row!(
+                row_key,
+                vec![
+                    smoltable::cell!("value:", Some(ts), CellValue::I64(ts as i64)),
+                    smoltable::cell!("another:", Some(ts), CellValue::I64(ts as i64))
+                ]
+            ))?;
+        }
+    }
+
+    // NOTE: This row has no versions in the time range
+    writer.write(&smoltable::row!(
+        "b1",
+        vec![smoltable::cell!("value:", Some(500), CellValue::I64(500))]
+    ))?;
+
+    writer.finalize()?;
+
+    let query_result = table.scan(QueryPrefixInput {
+        column: None,
+        cell: Some(QueryPrefixCellOptions {
+            limit: None,
+            time: Some(TimeRange {
+                start: Some(10),
+                end: Some(20),
+            }),
+        }),
+        row: QueryPrefixRowOptions {
+            scan: ScanMode::Prefix(String::new()),
+            cell_limit: None,
+            offset: None,
+            limit: None,
+            sample: None,
+            ..Default::default()
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(query_result.affected_locality_groups, 2);
+    assert_eq!(3, query_result.rows.len());
+
+    for row in &query_result.rows {
+        assert_eq!(20, row.cell_count());
+
+        for column in row.columns.values().flat_map(|family| family.values()) {
+            assert_eq!(
+                (10..20).rev().collect::<Vec<_>>(),
+                column.iter().map(|x| x.timestamp).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    let count_result = table.scan_count(CountInput {
+        column: None,
+        cell: Some(CountCellOptions {
+            time: Some(TimeRange {
+                start: Some(90),
+                end: None,
+            }),
+        }),
+        row: CountRowOptions {
+            scan: ScanMode::Prefix(String::new()),
+        },
+        ..Default::default()
+    })?;
+
+    assert_eq!(4, count_result.row_count);
+    assert_eq!(61, count_result.cell_count);
+
+    Ok(())
+}
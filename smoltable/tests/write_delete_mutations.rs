@@ -0,0 +1,237 @@
+mod common;
+
+use smoltable::{
+    query::{
+        row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+        scan::TimeRange,
+    },
+    CellValue, ColumnKey, DeleteWriteItem, RowWriteItem, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn create_table(folder: &std::path::Path) -> smoltable::Result<Smoltable> {
+    let table = common::create_table(folder)?;
+    common::create_column_family(&table, "another", Some(true))?;
+
+    let mut writer = TableWriter::new(table.clone());
+
+    for row_key in ["a", "b"] {
+        for ts in 0..5 {
+            writer.write(&smoltable::row!(
+                row_key,
+                vec![
+                    smoltable::cell!("value:", Some(ts), CellValue::I32(0)),
+                    smoltable::cell!("value:asd", Some(ts), CellValue::I32(1)),
+                    smoltable::cell!("another:asd", Some(ts), CellValue::I32(2))
+                ]
+            ))?;
+        }
+    }
+
+    writer.finalize()?;
+
+    Ok(table)
+}
+
+fn get_row(table: &Smoltable, row_key: &str) -> smoltable::Result<serde_json::Value> {
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: row_key.to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+
+    Ok(serde_json::to_value(query_result.row.map(|row| row.columns)).unwrap())
+}
+
+fn version_count(row: &serde_json::Value, family: &str, qualifier: &str) -> usize {
+    row[family][qualifier]
+        .as_array()
+        .map(Vec::len)
+        .unwrap_or_default()
+}
+
+#[test]
+pub fn write_delete_column() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    TableWriter::write_batch(
+        table.clone(),
+        &[
+            RowWriteItem {
+                row_key: "a".into(),
+                cells: vec![],
+                deletes: vec![DeleteWriteItem::Column {
+                    column_key: ColumnKey::try_from("value:").unwrap(),
+                    time: None,
+                }],
+            },
+            RowWriteItem {
+                row_key: "a".into(),
+                cells: vec![],
+                deletes: vec![DeleteWriteItem::Column {
+                    column_key: ColumnKey::try_from("another:asd").unwrap(),
+                    time: Some(TimeRange {
+                        start: Some(1),
+                        end: Some(3),
+                    }),
+                }],
+            },
+        ],
+    )?;
+
+    let row = get_row(&table, "a")?;
+    assert_eq!(0, version_count(&row, "value", ""));
+    assert_eq!(5, version_count(&row, "value", "asd"));
+    assert_eq!(3, version_count(&row, "another", "asd"));
+    assert_eq!(
+        serde_json::json!([4, 3, 0]),
+        serde_json::json!(row["another"]["asd"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|cell| cell["time"].clone())
+            .collect::<Vec<_>>())
+    );
+
+    let row = get_row(&table, "b")?;
+    assert_eq!(5, version_count(&row, "value", ""));
+
+    Ok(())
+}
+
+#[test]
+pub fn write_delete_family() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    TableWriter::write_batch(
+        table.clone(),
+        &[RowWriteItem {
+            row_key: "a".into(),
+            cells: vec![],
+            deletes: vec![DeleteWriteItem::Family("another".into())],
+        }],
+    )?;
+
+    let row = get_row(&table, "a")?;
+    assert!(row.get("another").is_none());
+    assert_eq!(5, version_count(&row, "value", ""));
+    assert_eq!(5, version_count(&row, "value", "asd"));
+
+    Ok(())
+}
+
+#[test]
+pub fn write_delete_row_and_set() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    // NOTE: Deletes are applied before the cells are set, so the row is replaced
+    TableWriter::write_batch(
+        table.clone(),
+        &[RowWriteItem {
+            row_key: "a".into(),
+            cells: vec![smoltable::cell!("value:new", Some(0), CellValue::I32(5))],
+            deletes: vec![DeleteWriteItem::Row],
+        }],
+    )?;
+
+    let row = get_row(&table, "a")?;
+    assert_eq!(
+        serde_json::json!({
+            "value": {
+                "new": [
+                    { "time": 0, "type": "i32", "value": 5 }
+                ]
+            }
+        }),
+        row
+    );
+
+    let (row_count, cell_count) = table.count()?;
+    assert_eq!(2, row_count);
+    assert_eq!(16, cell_count);
+
+    Ok(())
+}
+
+#[test]
+pub fn write_delete_in_batch_order() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+    let table = create_table(folder.path())?;
+
+    // NOTE: Deletes also affect cells set by earlier items of the batch
+    TableWriter::write_batch(
+        table.clone(),
+        &[
+            RowWriteItem {
+                row_key: "c".into(),
+                cells: vec![
+                    smoltable::cell!("value:asd", Some(0), CellValue::I32(0)),
+                    smoltable::cell!("value:asd", Some(5), CellValue::I32(1)),
+                    smoltable::cell!("another:asd", Some(0), CellValue::I32(2)),
+                ],
+                deletes: vec![],
+            },
+            RowWriteItem {
+                row_key: "c".into(),
+                cells: vec![],
+                deletes: vec![DeleteWriteItem::Column {
+                    column_key: ColumnKey::try_from("value:asd").unwrap(),
+                    time: Some(TimeRange {
+                        start: Some(3),
+                        end: None,
+                    }),
+                }],
+            },
+            RowWriteItem {
+                row_key: "a".into(),
+                cells: vec![smoltable::cell!("value:new", Some(0), CellValue::I32(3))],
+                deletes: vec![],
+            },
+            RowWriteItem {
+                row_key: "a".into(),
+                cells: vec![smoltable::cell!("value:newer", Some(0), CellValue::I32(4))],
+                deletes: vec![DeleteWriteItem::Row],
+            },
+        ],
+    )?;
+
+    let row = get_row(&table, "c")?;
+    assert_eq!(1, version_count(&row, "value", "asd"));
+    assert_eq!(1, version_count(&row, "another", "asd"));
+
+    let row = get_row(&table, "a")?;
+    assert_eq!(
+        serde_json::json!({
+            "value": {
+                "newer": [
+                    { "time": 0, "type": "i32", "value": 4 }
+                ]
+            }
+        }),
+        row
+    );
+
+    Ok(())
+}
+
+#[test]
+pub fn write_delete_deserialize() {
+    let item: RowWriteItem = serde_json::from_value(serde_json::json!({
+        "row_key": "a",
+        "cells": [],
+        "deletes": [
+            { "column": { "column_key": "value:asd", "time": { "start": 0, "end": 5 } } },
+            { "family": "another" },
+            "row"
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(3, item.deletes.len());
+}
@@ -0,0 +1,56 @@
+use smoltable::{
+    CellValue, ColumnFamilyDefinition, CreateColumnFamilyInput, GarbageCollectionOptions,
+    Smoltable, TableWriter,
+};
+use test_log::test;
+
+#[test]
+pub fn write_invalid_array() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![ColumnFamilyDefinition {
+            name: "value".to_owned(),
+            gc_settings: GarbageCollectionOptions {
+                ttl_secs: None,
+                version_limit: None,
+            },
+            ..Default::default()
+        }],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    for value in [
+        CellValue::Array(vec![CellValue::Array(vec![])]),
+        CellValue::Array(vec![CellValue::I32(0), CellValue::I64(0)]),
+    ] {
+        let result = TableWriter::write_batch(
+            table.clone(),
+            &[smoltable::row!(
+                "a",
+                vec![smoltable::cell!("value:tags", None, value)]
+            )],
+        );
+        assert!(matches!(result, Err(smoltable::Error::InvalidValueType(_))));
+    }
+
+    TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "a",
+            vec![smoltable::cell!(
+                "value:tags",
+                None,
+                CellValue::Array(vec![CellValue::I32(0), CellValue::I32(1)])
+            )]
+        )],
+    )?;
+
+    assert_eq!(1, table.tree.len()?);
+
+    Ok(())
+}
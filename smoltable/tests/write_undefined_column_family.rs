@@ -0,0 +1,145 @@
+mod common;
+
+use smoltable::{
+    query::row::{Input as QueryRowInput, RowOptions as QueryRowInputRowOptions},
+    CellValue, SchemaMode, Smoltable, TableWriter,
+};
+use test_log::test;
+
+fn get_cell_count(table: &Smoltable, row_key: &str) -> smoltable::Result<usize> {
+    let query_result = table.get_row(QueryRowInput {
+        row: QueryRowInputRowOptions {
+            key: row_key.to_owned(),
+            cell_limit: None,
+        },
+        column: None,
+        ..Default::default()
+    })?;
+
+    Ok(query_result
+        .row
+        .map(|row| row.cell_count())
+        .unwrap_or_default())
+}
+
+#[test]
+pub fn write_undefined_column_family_strict() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+    assert_eq!(SchemaMode::Strict, table.schema_mode());
+
+    common::create_column_family(&table, "value", None)?;
+
+    let result = TableWriter::write_batch(
+        table.clone(),
+        &[
+            smoltable::row!(
+                "a",
+                vec![smoltable::cell!("value:", Some(0), CellValue::I32(0))]
+            ),
+            smoltable::row!(
+                "b",
+                vec![
+                    smoltable::cell!("meta:", Some(0), CellValue::I32(0)),
+                    smoltable::cell!("anchor:x", Some(0), CellValue::I32(0)),
+                    smoltable::cell!("meta:size", Some(0), CellValue::I32(0)),
+                ]
+            ),
+        ],
+    );
+
+    match result {
+        Err(smoltable::Error::UndefinedColumnFamilies(families)) => {
+            assert_eq!(vec!["anchor", "meta"], families);
+        }
+        other => panic!("expected undefined column families, got {other:?}"),
+    }
+
+    // NOTE: The entire batch is rejected
+    assert_eq!(0, get_cell_count(&table, "a")?);
+    assert_eq!(0, get_cell_count(&table, "b")?);
+
+    TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "a",
+            vec![smoltable::cell!("value:", Some(0), CellValue::I32(0))]
+        )],
+    )?;
+
+    assert_eq!(1, get_cell_count(&table, "a")?);
+
+    Ok(())
+}
+
+#[test]
+pub fn write_undefined_column_family_permissive() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    {
+        let keyspace = fjall::Config::new(folder.path()).open()?;
+        let table = Smoltable::open("test", keyspace.clone())?;
+        common::create_column_family(&table, "value", None)?;
+
+        table.set_schema_mode(SchemaMode::Permissive)?;
+    }
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+    assert_eq!(SchemaMode::Permissive, table.schema_mode());
+
+    TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "a",
+            vec![
+                smoltable::cell!("value:", Some(0), CellValue::I32(0)),
+                smoltable::cell!("meta:", Some(0), CellValue::I32(0)),
+            ]
+        )],
+    )?;
+
+    assert_eq!(2, get_cell_count(&table, "a")?);
+
+    Ok(())
+}
+
+#[test]
+pub fn write_undefined_column_family_existing_table() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    {
+        let keyspace = fjall::Config::new(folder.path()).open()?;
+        let table = Smoltable::open("test", keyspace.clone())?;
+        common::create_column_family(&table, "value", None)?;
+
+        // NOTE: Simulate a table created before the schema mode existed
+        table.manifest.remove("schema#mode")?;
+        keyspace.persist(fjall::PersistMode::SyncAll)?;
+    }
+
+    {
+        let keyspace = fjall::Config::new(folder.path()).open()?;
+        let table = Smoltable::open("test", keyspace.clone())?;
+        assert_eq!(SchemaMode::Permissive, table.schema_mode());
+
+        TableWriter::write_batch(
+            table.clone(),
+            &[smoltable::row!(
+                "a",
+                vec![smoltable::cell!("meta:", Some(0), CellValue::I32(0))]
+            )],
+        )?;
+
+        assert_eq!(1, get_cell_count(&table, "a")?);
+    }
+
+    // NOTE: The schema mode is persisted during the first open
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+    assert_eq!(SchemaMode::Permissive, table.schema_mode());
+
+    Ok(())
+}
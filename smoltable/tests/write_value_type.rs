@@ -0,0 +1,94 @@
+use smoltable::{
+    CellValue, CellValueType, ColumnFamilyDefinition, CreateColumnFamilyInput,
+    GarbageCollectionOptions, Smoltable, TableWriter, ValueTypeDefinition,
+};
+use std::collections::BTreeMap;
+use test_log::test;
+
+#[test]
+pub fn write_value_type() -> smoltable::Result<()> {
+    let folder = tempfile::tempdir()?;
+
+    let keyspace = fjall::Config::new(folder.path()).open()?;
+    let table = Smoltable::open("test", keyspace.clone())?;
+
+    table.create_column_families(&CreateColumnFamilyInput {
+        column_families: vec![
+            ColumnFamilyDefinition {
+                name: "metrics".to_owned(),
+                gc_settings: GarbageCollectionOptions {
+                    ttl_secs: None,
+                    version_limit: None,
+                },
+                value_type: Some(ValueTypeDefinition {
+                    family: Some(CellValueType::F64),
+                    qualifiers: BTreeMap::new(),
+                }),
+            },
+            ColumnFamilyDefinition {
+                name: "profile".to_owned(),
+                gc_settings: GarbageCollectionOptions {
+                    ttl_secs: None,
+                    version_limit: None,
+                },
+                value_type: Some(ValueTypeDefinition {
+                    family: None,
+                    qualifiers: BTreeMap::from([("email".to_owned(), CellValueType::String)]),
+                }),
+            },
+        ],
+        locality_group: None,
+        ..Default::default()
+    })?;
+
+    TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "a",
+            vec![
+                smoltable::cell!("metrics:cpu", None, CellValue::F64(0.5)),
+                smoltable::cell!("profile:email", None, CellValue::String("a@b.c".into())),
+                smoltable::cell!("profile:age", None, CellValue::I32(30)),
+            ]
+        )],
+    )?;
+
+    let result = TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "a",
+            vec![smoltable::cell!("metrics:cpu", None, CellValue::I64(1))]
+        )],
+    );
+    assert!(matches!(
+        result,
+        Err(smoltable::Error::ValueTypeMismatch(_, CellValueType::F64))
+    ));
+
+    let result = TableWriter::write_batch(
+        table.clone(),
+        &[smoltable::row!(
+            "a",
+            vec![smoltable::cell!("profile:email", None, CellValue::I32(0))]
+        )],
+    );
+    assert!(matches!(
+        result,
+        Err(smoltable::Error::ValueTypeMismatch(
+            _,
+            CellValueType::String
+        ))
+    ));
+
+    let column_families = table.list_column_families()?;
+    let metrics = column_families
+        .iter()
+        .find(|cf| cf.name == "metrics")
+        .unwrap();
+    assert_eq!(
+        Some(CellValueType::F64),
+        metrics.value_type.as_ref().unwrap().family
+    );
+
+    Ok(())
+}